@@ -0,0 +1,95 @@
+use crate::db::{self, ApprovalStatus, WriteApproval, WriteMode};
+use crate::formats::OutputFormat;
+use chrono::Utc;
+use std::thread;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// How long an MCP write waits for the user before it is denied; under the
+/// 60 seconds common MCP clients wait for a tool call
+pub const TIMEOUT: Duration = Duration::from_secs(50);
+
+/// How often a waiting write checks for the user's decision
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How often the desktop app looks for new requests
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Write an MCP client wants to make
+pub struct WriteRequest<'a> {
+    pub client: &'a str,
+    pub tool: &'a str,
+    pub keys: Vec<String>,
+    pub tag: Option<String>,
+    /// Resolved target file
+    pub path: String,
+    pub project: Option<String>,
+    pub environment: Option<String>,
+    /// Template the variables come from, for `fill_env_from_template`
+    pub template: Option<String>,
+    pub mode: WriteMode,
+    pub format: OutputFormat,
+}
+
+/// Queue `request` for the user and block until it is decided, passing the
+/// approval's ID to `on_queued` once it is queued. Succeeds if the user
+/// approves, or a rule approves it without asking; a denial or timeout is an
+/// error.
+pub fn wait_for_approval(
+    request: WriteRequest,
+    on_queued: impl FnOnce(&str),
+) -> Result<(), String> {
+    let approval = WriteApproval {
+        id: Uuid::new_v4().to_string(),
+        client: request.client.to_string(),
+        tool: request.tool.to_string(),
+        keys: request.keys,
+        tag: request.tag,
+        path: request.path,
+        project: request.project,
+        environment: request.environment,
+        template: request.template,
+        mode: request.mode,
+        format: request.format,
+        created_at: Utc::now().timestamp(),
+    };
+    if !db::queue_write_approval(&approval)? {
+        return Ok(());
+    }
+    on_queued(&approval.id);
+
+    let deadline = Instant::now() + TIMEOUT;
+    let status = loop {
+        match db::get_write_approval_status(&approval.id)? {
+            ApprovalStatus::Pending if Instant::now() >= deadline => {
+                break db::expire_write_approval(&approval.id)?;
+            }
+            ApprovalStatus::Pending => thread::sleep(POLL_INTERVAL),
+            status => break status,
+        }
+    };
+
+    match status {
+        ApprovalStatus::Approved => Ok(()),
+        ApprovalStatus::Denied => Err(format!("The user denied writing to {}", approval.path)),
+        ApprovalStatus::Pending | ApprovalStatus::Expired => Err(format!(
+            "Writing to {} was not approved within {} seconds",
+            approval.path,
+            TIMEOUT.as_secs()
+        )),
+    }
+}
+
+/// Start watching for writes to approve in the desktop app; `on_request`
+/// runs once for each new one, whichever process queued it
+pub fn spawn<F: Fn(WriteApproval) + Send + 'static>(on_request: F) {
+    thread::spawn(move || loop {
+        thread::sleep(CHECK_INTERVAL);
+        let result = db::expire_write_approvals(TIMEOUT.as_secs() as i64)
+            .and_then(|()| db::take_new_write_approvals());
+        match result {
+            Ok(approvals) => approvals.into_iter().for_each(&on_request),
+            Err(e) => eprintln!("Write approval check failed: {}", e),
+        }
+    });
+}
@@ -0,0 +1,164 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// Replace `path` with `content` so that readers see either the old file or
+/// the complete new one. The data goes to a 0600 temp file in the same
+/// directory, is synced to disk and then renamed over the target. Symlinks
+/// and non-regular files at the target are refused.
+pub fn write(path: &Path, content: &[u8]) -> Result<(), String> {
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_symlink() => {
+            return Err(format!(
+                "Refusing to write through symlink {}",
+                path.display()
+            ));
+        }
+        Ok(metadata) if !metadata.is_file() => {
+            return Err(format!("{} is not a regular file", path.display()));
+        }
+        Ok(_) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e.to_string()),
+    }
+
+    let dir = path
+        .parent()
+        .ok_or_else(|| format!("{} has no parent directory", path.display()))?;
+    let temp_path = temp_path_for(path, dir)?;
+
+    let result = write_temp(&temp_path, content)
+        .and_then(|()| fs::rename(&temp_path, path))
+        .map_err(|e| e.to_string());
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result?;
+
+    sync_dir(dir);
+    Ok(())
+}
+
+/// Hidden, unique sibling of `path`, so the rename stays on one filesystem
+fn temp_path_for(path: &Path, dir: &Path) -> Result<PathBuf, String> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| format!("{} has no file name", path.display()))?;
+    Ok(dir.join(format!(
+        ".{}.{}.tmp",
+        file_name.to_string_lossy(),
+        Uuid::new_v4().simple()
+    )))
+}
+
+fn write_temp(temp_path: &Path, content: &[u8]) -> std::io::Result<()> {
+    // create_new refuses to reuse (or follow a symlink at) an existing path
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let mut file = options.open(temp_path)?;
+    file.write_all(content)?;
+    file.sync_all()
+}
+
+/// Persist the rename itself; best effort, as not every platform can open
+/// a directory
+fn sync_dir(dir: &Path) {
+    #[cfg(unix)]
+    if let Ok(dir) = fs::File::open(dir) {
+        let _ = dir.sync_all();
+    }
+    #[cfg(not(unix))]
+    let _ = dir;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn entries(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn writes_owner_only_files() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = tempdir().unwrap();
+        let dir = temp.path();
+        let path = dir.join(".env");
+        fs::write(&path, "OLD=1\n").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        write(&path, b"NEW=1\n").unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        let fresh = dir.join("fresh.env");
+        write(&fresh, b"A=1\n").unwrap();
+        let mode = fs::metadata(&fresh).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn replaces_the_file_instead_of_rewriting_it() {
+        let temp = tempdir().unwrap();
+        let dir = temp.path();
+        let path = dir.join(".env");
+        fs::write(&path, "OLD=1\n").unwrap();
+        // A reader holding the old file keeps seeing complete old content
+        let reader = dir.join("reader");
+        fs::hard_link(&path, &reader).unwrap();
+
+        write(&path, b"NEW=1\n").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "NEW=1\n");
+        assert_eq!(fs::read_to_string(&reader).unwrap(), "OLD=1\n");
+        assert_eq!(entries(dir), [".env", "reader"]);
+    }
+
+    #[test]
+    fn failed_write_leaves_no_temp_file() {
+        let temp = tempdir().unwrap();
+        let dir = temp.path();
+        let target = dir.join("target");
+        fs::create_dir(&target).unwrap();
+
+        assert!(write(&target, b"A=1\n").is_err());
+        assert!(write(&dir.join("missing").join(".env"), b"A=1\n").is_err());
+        assert_eq!(entries(dir), ["target"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn refuses_symlinks() {
+        let temp = tempdir().unwrap();
+        let dir = temp.path();
+        let real = dir.join("real.env");
+        fs::write(&real, "KEEP=1\n").unwrap();
+        let link = dir.join(".env");
+        std::os::unix::fs::symlink(&real, &link).unwrap();
+
+        let err = write(&link, b"A=1\n").unwrap_err();
+        assert!(err.contains("symlink"), "{}", err);
+        assert_eq!(fs::read_to_string(&real).unwrap(), "KEEP=1\n");
+        assert!(fs::symlink_metadata(&link)
+            .unwrap()
+            .file_type()
+            .is_symlink());
+        assert_eq!(entries(dir), [".env", "real.env"]);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{db, vault};
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often the idle timer checks whether the vault should be locked
+const CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Time of the last call into a Tauri command
+static LAST_ACTIVITY: Lazy<Mutex<Instant>> = Lazy::new(|| Mutex::new(Instant::now()));
+
+/// Record user activity, restarting the idle period
+pub fn touch() {
+    if let Ok(mut last) = LAST_ACTIVITY.lock() {
+        *last = Instant::now();
+    }
+}
+
+/// Time elapsed since the last recorded activity
+fn idle_for() -> Duration {
+    LAST_ACTIVITY
+        .lock()
+        .map(|last| last.elapsed())
+        .unwrap_or_default()
+}
+
+/// Lock the vault if it has been idle longer than the configured timeout.
+/// Returns true if the vault was locked by this call.
+fn check() -> Result<bool, String> {
+    let minutes = db::get_auto_lock_minutes()?;
+    if minutes == 0 || !vault::is_unlocked()? {
+        return Ok(false);
+    }
+
+    if idle_for() < Duration::from_secs(u64::from(minutes) * 60) {
+        return Ok(false);
+    }
+
+    vault::lock()?;
+    Ok(true)
+}
+
+/// Start the idle timer; `on_lock` runs each time the vault is auto-locked
+pub fn spawn<F: Fn() + Send + 'static>(on_lock: F) {
+    thread::spawn(move || loop {
+        thread::sleep(CHECK_INTERVAL);
+        match check() {
+            Ok(true) => on_lock(),
+            Ok(false) => {}
+            Err(e) => eprintln!("Auto-lock check failed: {}", e),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TestApp;
+
+    /// Pretend the last activity happened `ago` in the past
+    fn idle(ago: Duration) {
+        *LAST_ACTIVITY.lock().unwrap() = Instant::now() - ago;
+    }
+
+    #[test]
+    fn locks_after_the_timeout() {
+        let _app = TestApp::new();
+        db::set_auto_lock_minutes(5).unwrap();
+
+        idle(Duration::from_secs(4 * 60));
+        assert!(!check().unwrap());
+        assert!(vault::is_unlocked().unwrap());
+
+        idle(Duration::from_secs(5 * 60));
+        assert!(check().unwrap());
+        assert!(!vault::is_unlocked().unwrap());
+        // Already locked, nothing more to do
+        assert!(!check().unwrap());
+    }
+
+    #[test]
+    fn touch_postpones_the_lock() {
+        let _app = TestApp::new();
+        db::set_auto_lock_minutes(5).unwrap();
+
+        idle(Duration::from_secs(10 * 60));
+        touch();
+
+        assert!(!check().unwrap());
+        assert!(vault::is_unlocked().unwrap());
+    }
+
+    #[test]
+    fn zero_minutes_never_locks() {
+        let _app = TestApp::new();
+        db::set_auto_lock_minutes(0).unwrap();
+
+        idle(Duration::from_secs(60 * 60));
+
+        assert!(!check().unwrap());
+        assert!(vault::is_unlocked().unwrap());
+    }
+}
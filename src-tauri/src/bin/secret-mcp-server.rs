@@ -0,0 +1,3 @@
+fn main() {
+    secret_mcp_lib::run_mcp_server();
+}
@@ -1,4 +1,9 @@
+use crate::autolock;
 use crate::db;
+use crate::git_check::{GitCheck, GitPolicy};
+use crate::mcp_http;
+use crate::path_policy::PathPolicy;
+use crate::vault;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -6,6 +11,8 @@ pub struct CreateSecretInput {
     pub name: String,
     pub description: Option<String>,
     pub value: String,
+    pub project_id: Option<String>,
+    pub tags: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -14,6 +21,14 @@ pub struct UpdateSecretInput {
     pub name: String,
     pub description: Option<String>,
     pub value: String,
+    pub project_id: Option<String>,
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProjectInput {
+    pub name: String,
+    pub description: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -21,62 +36,469 @@ pub struct WriteEnvResult {
     pub success: bool,
     pub written: usize,
     pub missing: Vec<String>,
+    /// What would change, for dry runs
+    pub diff: Option<db::EnvDiff>,
+    /// Whether the file is in a git working tree and ignored there
+    pub git: GitCheck,
+    /// Variable written for each secret, and whether it was found
+    pub mappings: Vec<db::KeyMapping>,
 }
 
-/// List all secrets (values masked)
+impl From<db::WriteEnvReport> for WriteEnvResult {
+    fn from(report: db::WriteEnvReport) -> Self {
+        WriteEnvResult {
+            success: true,
+            written: report.written,
+            missing: report.missing,
+            diff: report.diff,
+            git: report.git,
+            mappings: report.mappings,
+        }
+    }
+}
+
+/// List all secrets, optionally only those of one project (values masked)
 #[tauri::command]
-pub fn list_secrets() -> Result<Vec<db::SecretInfo>, String> {
-    db::list_secrets()
+pub fn list_secrets(project_id: Option<String>) -> Result<Vec<db::SecretInfo>, String> {
+    autolock::touch();
+    db::list_secrets(project_id.as_deref())
 }
 
 /// Get a single secret by ID (includes value for editing)
 #[tauri::command]
 pub fn get_secret(id: String) -> Result<Option<db::Secret>, String> {
-    db::get_secret(&id)
+    autolock::touch();
+    db::get_secret(&db::Actor::Desktop, &id)
 }
 
 /// Create a new secret
 #[tauri::command]
 pub fn create_secret(input: CreateSecretInput) -> Result<db::Secret, String> {
-    db::create_secret(&input.name, input.description.as_deref(), &input.value)
+    autolock::touch();
+    db::create_secret(
+        &db::Actor::Desktop,
+        &input.name,
+        input.description.as_deref(),
+        &input.value,
+        input.project_id.as_deref(),
+        &input.tags.unwrap_or_default(),
+    )
 }
 
 /// Update an existing secret
 #[tauri::command]
 pub fn update_secret(input: UpdateSecretInput) -> Result<db::Secret, String> {
+    autolock::touch();
     db::update_secret(
+        &db::Actor::Desktop,
         &input.id,
         &input.name,
         input.description.as_deref(),
         &input.value,
+        input.project_id.as_deref(),
+        input.tags.as_deref(),
     )
 }
 
-/// Delete a secret
+/// Move a secret to the trash
 #[tauri::command]
 pub fn delete_secret(id: String) -> Result<bool, String> {
-    db::delete_secret(&id)
+    autolock::touch();
+    db::delete_secret(&db::Actor::Desktop, &id)
+}
+
+/// List secrets in the trash
+#[tauri::command]
+pub fn list_trash() -> Result<Vec<db::TrashedSecretInfo>, String> {
+    autolock::touch();
+    db::list_trash()
+}
+
+/// Move a secret out of the trash
+#[tauri::command]
+pub fn restore_secret(id: String) -> Result<bool, String> {
+    autolock::touch();
+    db::restore_secret(&db::Actor::Desktop, &id)
+}
+
+/// Permanently delete one trashed secret, or the whole trash
+#[tauri::command]
+pub fn purge_trash(id: Option<String>) -> Result<usize, String> {
+    autolock::touch();
+    db::purge_trash(&db::Actor::Desktop, id.as_deref())
+}
+
+/// List previous values of a secret (values masked)
+#[tauri::command]
+pub fn list_secret_versions(secret_id: String) -> Result<Vec<db::SecretVersionInfo>, String> {
+    autolock::touch();
+    db::list_secret_versions(&secret_id)
+}
+
+/// Restore a previous value of a secret
+#[tauri::command]
+pub fn restore_secret_version(version_id: i64) -> Result<db::Secret, String> {
+    autolock::touch();
+    db::restore_secret_version(&db::Actor::Desktop, version_id)
+}
+
+/// List all projects
+#[tauri::command]
+pub fn list_projects() -> Result<Vec<db::Project>, String> {
+    autolock::touch();
+    db::list_projects()
+}
+
+/// Create a new project
+#[tauri::command]
+pub fn create_project(input: ProjectInput) -> Result<db::Project, String> {
+    autolock::touch();
+    db::create_project(&input.name, input.description.as_deref())
+}
+
+/// Rename or re-describe a project
+#[tauri::command]
+pub fn update_project(id: String, input: ProjectInput) -> Result<db::Project, String> {
+    autolock::touch();
+    db::update_project(&id, &input.name, input.description.as_deref())
+}
+
+/// Delete an empty project
+#[tauri::command]
+pub fn delete_project(id: String) -> Result<bool, String> {
+    autolock::touch();
+    db::delete_project(&id)
 }
 
-/// Search secrets by name or description
+/// List all environments
 #[tauri::command]
-pub fn search_secrets(query: String) -> Result<Vec<db::SecretSearchResult>, String> {
-    db::search_secrets(&query)
+pub fn list_environments() -> Result<Vec<db::Environment>, String> {
+    autolock::touch();
+    db::list_environments()
 }
 
-/// Write secrets to a .env file
+/// Create a new environment
 #[tauri::command]
-pub fn write_env(keys: Vec<String>, path: String) -> Result<WriteEnvResult, String> {
-    let (written, missing) = db::write_env_file(&keys, &path)?;
-    Ok(WriteEnvResult {
-        success: true,
-        written,
-        missing,
-    })
+pub fn create_environment(name: String) -> Result<db::Environment, String> {
+    autolock::touch();
+    db::create_environment(&name)
+}
+
+/// Rename an environment, keeping its values
+#[tauri::command]
+pub fn rename_environment(name: String, new_name: String) -> Result<bool, String> {
+    autolock::touch();
+    db::rename_environment(&name, &new_name)
+}
+
+/// Delete an environment that no secret has a value for
+#[tauri::command]
+pub fn delete_environment(name: String) -> Result<bool, String> {
+    autolock::touch();
+    db::delete_environment(&name)
+}
+
+/// List the per-environment values of a secret
+#[tauri::command]
+pub fn list_secret_environment_values(
+    secret_id: String,
+) -> Result<Vec<db::SecretEnvironmentValue>, String> {
+    autolock::touch();
+    db::list_secret_environment_values(&db::Actor::Desktop, &secret_id)
+}
+
+/// Set the value a secret takes in one environment
+#[tauri::command]
+pub fn set_secret_environment_value(
+    secret_id: String,
+    environment: String,
+    value: String,
+) -> Result<(), String> {
+    autolock::touch();
+    db::set_secret_environment_value(&db::Actor::Desktop, &secret_id, &environment, &value)
+}
+
+/// Remove a secret's value for one environment so it falls back to the default
+#[tauri::command]
+pub fn clear_secret_environment_value(
+    secret_id: String,
+    environment: String,
+) -> Result<bool, String> {
+    autolock::touch();
+    db::clear_secret_environment_value(&db::Actor::Desktop, &secret_id, &environment)
+}
+
+/// List every tag in use
+#[tauri::command]
+pub fn list_tags() -> Result<Vec<String>, String> {
+    autolock::touch();
+    db::list_tags()
+}
+
+/// Search secrets by name, description or `tag:` filter, optionally within a project
+#[tauri::command]
+pub fn search_secrets(
+    query: String,
+    project: Option<String>,
+) -> Result<Vec<db::SecretSearchResult>, String> {
+    autolock::touch();
+    db::search_secrets(&query, project.as_deref())
+}
+
+/// Write secrets to a .env file. `options` selects the project, environment,
+/// tag, output format and whether to overwrite or merge into an existing file.
+#[tauri::command]
+pub fn write_env(
+    keys: Vec<String>,
+    path: String,
+    options: Option<db::WriteEnvOptions>,
+) -> Result<WriteEnvResult, String> {
+    autolock::touch();
+    let report = db::write_env_file(
+        &db::Actor::Desktop,
+        &keys,
+        &path,
+        &options.unwrap_or_default(),
+    )?;
+    Ok(WriteEnvResult::from(report))
+}
+
+/// Fill an env file from a template such as `.env.example`, matching each
+/// variable to a secret by alias or name. `missing` lists unmatched variables.
+#[tauri::command]
+pub fn fill_env_from_template(
+    template: String,
+    path: String,
+    options: Option<db::WriteEnvOptions>,
+) -> Result<WriteEnvResult, String> {
+    autolock::touch();
+    let report = db::fill_env_from_template(
+        &db::Actor::Desktop,
+        &template,
+        &path,
+        &options.unwrap_or_default(),
+    )?;
+    Ok(WriteEnvResult::from(report))
+}
+
+/// Preview write_env: which keys would be added, changed, unchanged, removed
+/// or missing. Nothing is written and no values are returned.
+#[tauri::command]
+pub fn diff_env(
+    keys: Vec<String>,
+    path: String,
+    options: Option<db::WriteEnvOptions>,
+) -> Result<db::EnvDiff, String> {
+    autolock::touch();
+    let options = db::WriteEnvOptions {
+        dry_run: true,
+        ..options.unwrap_or_default()
+    };
+    let report = db::write_env_file(&db::Actor::Desktop, &keys, &path, &options)?;
+    Ok(report.diff.unwrap_or_default())
+}
+
+/// List backups of env files replaced by write_env, optionally for one file
+#[tauri::command]
+pub fn list_env_backups(path: Option<String>) -> Result<Vec<db::EnvBackup>, String> {
+    autolock::touch();
+    db::list_env_backups(path.as_deref())
+}
+
+/// Restore an env file from a backup, if the path policy and git check
+/// still allow writing it
+#[tauri::command]
+pub fn restore_env_backup(id: i64, git: Option<GitPolicy>) -> Result<db::EnvBackup, String> {
+    autolock::touch();
+    db::restore_env_backup(&db::Actor::Desktop, id, git.unwrap_or_default())
 }
 
 /// Get the database path (for MCP server configuration)
 #[tauri::command]
 pub fn get_db_path() -> String {
+    autolock::touch();
     db::get_db_path_string()
 }
+
+/// Get whether the vault has a master password and is unlocked
+#[tauri::command]
+pub fn vault_status() -> Result<vault::VaultStatus, String> {
+    autolock::touch();
+    db::vault_status()
+}
+
+/// Unlock the vault with the master password (sets it on first use)
+#[tauri::command]
+pub fn unlock_vault(password: String) -> Result<vault::VaultStatus, String> {
+    autolock::touch();
+    db::unlock_vault(&password)
+}
+
+/// Lock the vault
+#[tauri::command]
+pub fn lock_vault() -> Result<vault::VaultStatus, String> {
+    autolock::touch();
+    db::lock_vault()
+}
+
+/// Get the idle period in minutes after which the vault locks (0 = never)
+#[tauri::command]
+pub fn get_auto_lock_minutes() -> Result<u32, String> {
+    autolock::touch();
+    db::get_auto_lock_minutes()
+}
+
+/// Set the idle period in minutes after which the vault locks (0 = never)
+#[tauri::command]
+pub fn set_auto_lock_minutes(minutes: u32) -> Result<(), String> {
+    autolock::touch();
+    db::set_auto_lock_minutes(minutes)
+}
+
+/// Get how many previous values are kept per secret
+#[tauri::command]
+pub fn get_version_retention() -> Result<u32, String> {
+    autolock::touch();
+    db::get_version_retention()
+}
+
+/// Set how many previous values are kept per secret
+#[tauri::command]
+pub fn set_version_retention(versions: u32) -> Result<(), String> {
+    autolock::touch();
+    db::set_version_retention(versions)
+}
+
+/// Get how many days secrets stay in the trash
+#[tauri::command]
+pub fn get_trash_retention_days() -> Result<u32, String> {
+    autolock::touch();
+    db::get_trash_retention_days()
+}
+
+/// Set how many days secrets stay in the trash
+#[tauri::command]
+pub fn set_trash_retention_days(days: u32) -> Result<(), String> {
+    autolock::touch();
+    db::set_trash_retention_days(days)
+}
+
+/// Get how many backups are kept per env file
+#[tauri::command]
+pub fn get_env_backup_retention() -> Result<u32, String> {
+    autolock::touch();
+    db::get_env_backup_retention()
+}
+
+/// Set how many backups are kept per env file
+#[tauri::command]
+pub fn set_env_backup_retention(backups: u32) -> Result<(), String> {
+    autolock::touch();
+    db::set_env_backup_retention(backups)
+}
+
+/// Get the directories and patterns that decide where env files may be written
+#[tauri::command]
+pub fn get_path_policy() -> Result<PathPolicy, String> {
+    autolock::touch();
+    db::get_path_policy()
+}
+
+/// Set the directories and patterns that decide where env files may be written
+#[tauri::command]
+pub fn set_path_policy(policy: PathPolicy) -> Result<(), String> {
+    autolock::touch();
+    db::set_path_policy(&policy)
+}
+
+/// Start the MCP endpoint on 127.0.0.1 with a new bearer token
+#[tauri::command]
+pub fn start_mcp_http(port: Option<u16>) -> Result<mcp_http::HttpStatus, String> {
+    autolock::touch();
+    mcp_http::start(port.unwrap_or(mcp_http::DEFAULT_PORT))
+}
+
+/// Stop the MCP endpoint until it is started again or the app restarts
+#[tauri::command]
+pub fn stop_mcp_http() -> Result<mcp_http::HttpStatus, String> {
+    autolock::touch();
+    mcp_http::stop()
+}
+
+/// Get the MCP endpoint's URL and token, if it is running
+#[tauri::command]
+pub fn get_mcp_http_status() -> Result<mcp_http::HttpStatus, String> {
+    autolock::touch();
+    mcp_http::status()
+}
+
+/// Get whether the MCP endpoint starts with the app
+#[tauri::command]
+pub fn get_mcp_http_enabled() -> Result<bool, String> {
+    autolock::touch();
+    db::get_mcp_http_enabled()
+}
+
+/// Set whether the MCP endpoint starts with the app
+#[tauri::command]
+pub fn set_mcp_http_enabled(enabled: bool) -> Result<(), String> {
+    autolock::touch();
+    db::set_mcp_http_enabled(enabled)
+}
+
+/// List writes requested by MCP clients that wait for the user
+#[tauri::command]
+pub fn list_write_approvals() -> Result<Vec<db::WriteApproval>, String> {
+    autolock::touch();
+    db::list_write_approvals()
+}
+
+/// Let a requested write go ahead. `remember` also allows later writes for
+/// the same project or file without asking.
+#[tauri::command]
+pub fn approve_write(id: String, remember: Option<db::ApprovalScope>) -> Result<(), String> {
+    autolock::touch();
+    db::decide_write_approval(&id, true, remember)
+}
+
+/// Refuse a requested write
+#[tauri::command]
+pub fn deny_write(id: String) -> Result<(), String> {
+    autolock::touch();
+    db::decide_write_approval(&id, false, None)
+}
+
+/// List the rules that approve MCP writes without asking
+#[tauri::command]
+pub fn list_approval_rules() -> Result<Vec<db::ApprovalRule>, String> {
+    autolock::touch();
+    db::list_approval_rules()
+}
+
+/// Delete a rule, so the writes it covered need approval again
+#[tauri::command]
+pub fn delete_approval_rule(id: i64) -> Result<bool, String> {
+    autolock::touch();
+    db::delete_approval_rule(id)
+}
+
+/// Query the audit log of secret access and writes, newest first
+#[tauri::command]
+pub fn query_audit_log(filter: Option<db::AuditFilter>) -> Result<Vec<db::AuditEntry>, String> {
+    autolock::touch();
+    db::query_audit_log(&filter.unwrap_or_default())
+}
+
+/// Export matching audit log entries to a JSON Lines file
+#[tauri::command]
+pub fn export_audit_log(filter: Option<db::AuditFilter>, path: String) -> Result<usize, String> {
+    autolock::touch();
+    db::export_audit_log(&filter.unwrap_or_default(), &path)
+}
+
+/// Check that no audit log entry was modified, moved or removed
+#[tauri::command]
+pub fn verify_audit_log() -> Result<db::AuditVerification, String> {
+    autolock::touch();
+    db::verify_audit_log()
+}
@@ -0,0 +1,221 @@
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use std::path::Path;
+use zeroize::Zeroizing;
+
+/// Length of the vault data key in bytes
+pub const KEY_LEN: usize = 32;
+
+/// Length of the key derivation salt in bytes
+pub const SALT_LEN: usize = 16;
+
+/// Length of an XChaCha20-Poly1305 nonce in bytes
+const NONCE_LEN: usize = 24;
+
+/// Format byte prefixed to every encrypted value
+const FORMAT_V1: u8 = 1;
+
+/// Argon2id cost parameters, stored with the vault so they can be tuned later
+#[derive(Debug, Clone, Copy)]
+pub struct KdfParams {
+    /// Memory cost in KiB
+    pub m_cost: u32,
+    /// Number of iterations
+    pub t_cost: u32,
+    /// Degree of parallelism
+    pub p_cost: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self {
+            m_cost: 64 * 1024,
+            t_cost: 3,
+            p_cost: 1,
+        }
+    }
+}
+
+/// Generate a fresh random data key
+pub fn generate_key() -> Key {
+    XChaCha20Poly1305::generate_key(&mut OsRng)
+}
+
+/// Generate a random salt for key derivation
+pub fn generate_salt() -> Vec<u8> {
+    let mut salt = vec![0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    salt
+}
+
+/// Derive a key-encryption key from the master password with Argon2id
+pub fn derive_key(password: &str, salt: &[u8], params: &KdfParams) -> Result<Key, String> {
+    let params = Params::new(params.m_cost, params.t_cost, params.p_cost, Some(KEY_LEN))
+        .map_err(|e| e.to_string())?;
+    let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+
+    let mut key = Key::default();
+    argon2
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|e| e.to_string())?;
+    Ok(key)
+}
+
+/// Read a data key file written by versions without a master password
+pub fn read_key_file(path: &Path) -> Result<Option<Key>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let bytes = Zeroizing::new(std::fs::read(path).map_err(|e| e.to_string())?);
+    if bytes.len() != KEY_LEN {
+        return Err(format!("Invalid key file: {}", path.display()));
+    }
+    Ok(Some(*Key::from_slice(&bytes)))
+}
+
+/// Encrypt the data key with a key-encryption key
+pub fn wrap_key(kek: &Key, key: &Key) -> Result<Vec<u8>, String> {
+    encrypt_bytes(kek, key.as_slice())
+}
+
+/// Decrypt a data key wrapped by `wrap_key`
+pub fn unwrap_key(kek: &Key, wrapped: &[u8]) -> Result<Key, String> {
+    let bytes = Zeroizing::new(decrypt_bytes(kek, wrapped)?);
+    if bytes.len() != KEY_LEN {
+        return Err("Invalid wrapped key".to_string());
+    }
+    Ok(*Key::from_slice(&bytes))
+}
+
+/// Encrypt a value: format byte, then nonce, then ciphertext with tag.
+/// Values are not bound to their row: whoever can swap ciphertexts in the
+/// database can equally rewrite its path policy and approvals.
+pub fn encrypt(key: &Key, plaintext: &str) -> Result<Vec<u8>, String> {
+    encrypt_bytes(key, plaintext.as_bytes())
+}
+
+/// Decrypt a value produced by `encrypt`
+pub fn decrypt(key: &Key, blob: &[u8]) -> Result<String, String> {
+    let plaintext = decrypt_bytes(key, blob)?;
+    String::from_utf8(plaintext).map_err(|e| e.to_string())
+}
+
+fn encrypt_bytes(key: &Key, plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    let cipher = XChaCha20Poly1305::new(key);
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .map_err(|_| "Encryption failed".to_string())?;
+
+    let mut blob = Vec::with_capacity(1 + NONCE_LEN + ciphertext.len());
+    blob.push(FORMAT_V1);
+    blob.extend_from_slice(&nonce);
+    blob.extend_from_slice(&ciphertext);
+    Ok(blob)
+}
+
+fn decrypt_bytes(key: &Key, blob: &[u8]) -> Result<Vec<u8>, String> {
+    if blob.len() < 1 + NONCE_LEN || blob[0] != FORMAT_V1 {
+        return Err("Unsupported encrypted value format".to_string());
+    }
+
+    let cipher = XChaCha20Poly1305::new(key);
+    let nonce = XNonce::from_slice(&blob[1..1 + NONCE_LEN]);
+    cipher
+        .decrypt(nonce, &blob[1 + NONCE_LEN..])
+        .map_err(|_| "Decryption failed (wrong key or corrupted value)".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chacha20poly1305::aead::Payload;
+
+    // Test vector A.3.1 from draft-irtf-cfrg-xchacha-03
+    const KEY: &str = "808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f";
+    const NONCE: &str = "404142434445464748494a4b4c4d4e4f5051525354555657";
+    const AAD: &str = "50515253c0c1c2c3c4c5c6c7";
+    const PLAINTEXT: &str = "Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+    const CIPHERTEXT: &str = "bd6d179d3e83d43b9576579493c0e939572a1700252bfaccbed2902c21396cbb\
+                              731c7f1b0b4aa6440bf3a82f4eda7e39ae64c6708c54c216cb96b72e1213b452\
+                              2f8c9ba40db5d945b11b69b982c1bb9e3f3fac2bc369488f76b2383565d3fff9\
+                              21f9664c97637da9768812f615c68b13b52e";
+    const TAG: &str = "c0875924c1c7987947deafd8780acf49";
+    /// Tag for the same key, nonce and plaintext without associated data,
+    /// as values are encrypted, computed with libsodium's
+    /// `crypto_aead_xchacha20poly1305_ietf_encrypt`
+    const TAG_WITHOUT_AAD: &str = "f7e62efbf45089db18f9c8a3f0e41e5f";
+
+    fn unhex(hex: &str) -> Vec<u8> {
+        let hex: Vec<u8> = hex.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+        hex.chunks(2)
+            .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).unwrap(), 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn matches_published_test_vector() {
+        let cipher = XChaCha20Poly1305::new(Key::from_slice(&unhex(KEY)));
+        let nonce = unhex(NONCE);
+        let aad = unhex(AAD);
+        let expected = [unhex(CIPHERTEXT), unhex(TAG)].concat();
+
+        let sealed = cipher
+            .encrypt(
+                XNonce::from_slice(&nonce),
+                Payload {
+                    msg: PLAINTEXT.as_bytes(),
+                    aad: &aad,
+                },
+            )
+            .unwrap();
+        assert_eq!(sealed, expected);
+
+        let opened = cipher
+            .decrypt(
+                XNonce::from_slice(&nonce),
+                Payload {
+                    msg: &expected,
+                    aad: &aad,
+                },
+            )
+            .unwrap();
+        assert_eq!(opened, PLAINTEXT.as_bytes());
+    }
+
+    #[test]
+    fn decrypts_values_sealed_elsewhere() {
+        let key = *Key::from_slice(&unhex(KEY));
+        let blob = [
+            vec![FORMAT_V1],
+            unhex(NONCE),
+            unhex(CIPHERTEXT),
+            unhex(TAG_WITHOUT_AAD),
+        ]
+        .concat();
+        assert_eq!(decrypt(&key, &blob).unwrap(), PLAINTEXT);
+
+        let mut tampered = blob.clone();
+        tampered[1 + NONCE_LEN] ^= 1;
+        assert!(decrypt(&key, &tampered).is_err());
+        assert!(decrypt(&Key::default(), &blob).is_err());
+
+        let mut other_format = blob;
+        other_format[0] = 2;
+        assert!(decrypt(&key, &other_format).is_err());
+    }
+
+    #[test]
+    fn round_trips_with_fresh_nonces() {
+        let key = generate_key();
+        let first = encrypt(&key, "sk-test").unwrap();
+        let second = encrypt(&key, "sk-test").unwrap();
+        assert_ne!(first, second);
+        assert_eq!(first.len(), 1 + NONCE_LEN + "sk-test".len() + 16);
+        assert_eq!(decrypt(&key, &first).unwrap(), "sk-test");
+        assert_eq!(decrypt(&key, &second).unwrap(), "sk-test");
+    }
+}
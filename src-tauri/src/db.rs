@@ -1,7 +1,18 @@
+use crate::atomic_file;
+use crate::crypto;
+use crate::dotenv;
+use crate::formats::{KubernetesOptions, OutputFormat};
+use crate::git_check::{self, GitCheck, GitPolicy};
+use crate::migrations;
+use crate::path_policy::PathPolicy;
+use crate::vault;
 use chrono::Utc;
 use once_cell::sync::Lazy;
-use rusqlite::{params, Connection};
+use rusqlite::{params, Connection, OptionalExtension, Transaction, TransactionBehavior};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
 use std::path::PathBuf;
 use std::sync::Mutex;
 use uuid::Uuid;
@@ -10,8 +21,10 @@ use uuid::Uuid;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SecretInfo {
     pub id: String,
+    pub project_id: String,
     pub name: String,
     pub description: Option<String>,
+    pub tags: Vec<String>,
     pub created_at: i64,
     pub updated_at: i64,
 }
@@ -20,29 +33,442 @@ pub struct SecretInfo {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Secret {
     pub id: String,
+    pub project_id: String,
     pub name: String,
     pub description: Option<String>,
+    pub tags: Vec<String>,
     pub value: String,
     pub created_at: i64,
     pub updated_at: i64,
 }
 
-/// Search result (name and description only)
+/// Previous value of a secret (value masked)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretVersionInfo {
+    pub id: i64,
+    pub secret_id: String,
+    /// Environment whose value this was; `None` for the default value
+    pub environment: Option<String>,
+    pub replaced_at: i64,
+}
+
+/// Secret in the trash (value masked)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashedSecretInfo {
+    pub id: String,
+    pub project_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub deleted_at: i64,
+}
+
+/// Search result (name, description and tags only)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SecretSearchResult {
     pub name: String,
     pub description: Option<String>,
+    pub tags: Vec<String>,
+    /// Name of the project the secret belongs to
+    pub project: String,
+}
+
+/// Project that scopes secrets, so the same variable name can exist per project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Project {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// Deployment environment that can override a secret's default value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Environment {
+    pub name: String,
+    pub created_at: i64,
+}
+
+/// Value of a secret in one environment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretEnvironmentValue {
+    pub environment: String,
+    pub value: String,
+    pub updated_at: i64,
+}
+
+/// How `write_env_file` treats an existing file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WriteMode {
+    /// Replace the whole file
+    #[default]
+    Overwrite,
+    /// Update requested keys in place, keeping every other line (dotenv only)
+    Merge,
+}
+
+impl WriteMode {
+    fn as_str(self) -> &'static str {
+        match self {
+            WriteMode::Overwrite => "overwrite",
+            WriteMode::Merge => "merge",
+        }
+    }
+
+    fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "overwrite" => Ok(WriteMode::Overwrite),
+            "merge" => Ok(WriteMode::Merge),
+            _ => Err(format!("Invalid write mode: {}", value)),
+        }
+    }
+}
+
+/// Earlier contents of an env file replaced by `write_env_file`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnvBackup {
+    pub id: i64,
+    /// File the backup was taken from
+    pub path: String,
+    /// Size of the original contents in bytes
+    pub size: i64,
+    pub created_at: i64,
+}
+
+/// Options for `write_env_file`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WriteEnvOptions {
+    /// Project to resolve names in; its secrets win over global ones
+    pub project: Option<String>,
+    /// Environment whose values win over each secret's default
+    pub environment: Option<String>,
+    /// Also write every secret carrying this tag
+    pub tag: Option<String>,
+    pub mode: WriteMode,
+    /// Output format; inferred from the file name if not given
+    pub format: Option<OutputFormat>,
+    /// Secret name, namespace and labels for Kubernetes formats
+    pub kubernetes: Option<KubernetesOptions>,
+    /// Report what would change without writing anything
+    pub dry_run: bool,
+    /// What to do if the file would not be ignored by git
+    pub git: GitPolicy,
+    /// Extra variables to write, mapped to the secret that provides each
+    /// (e.g. `VITE_OPENAI_KEY` -> `OPENAI_API_KEY`)
+    pub aliases: BTreeMap<String, String>,
+    /// Prepended to the variable names of `keys` and tagged secrets (not aliases)
+    pub prefix: Option<String>,
+}
+
+/// Variable written for a secret, and whether the secret was found
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyMapping {
+    pub variable: String,
+    pub secret: String,
+    pub found: bool,
+}
+
+/// Keys a write would touch, grouped by what would happen to them. Built by
+/// comparing value hashes, so no values are included.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EnvDiff {
+    /// Not in the file yet
+    pub added: Vec<String>,
+    /// In the file with a different value
+    pub changed: Vec<String>,
+    /// In the file with the same value
+    pub unchanged: Vec<String>,
+    /// In the file but dropped by an overwrite
+    pub removed: Vec<String>,
+    /// Would be written to a file that is not dotenv, whose values are not
+    /// compared
+    #[serde(default)]
+    pub not_comparable: Vec<String>,
+    /// Requested but not in the vault
+    pub missing: Vec<String>,
+}
+
+/// Outcome of `write_env_file`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WriteEnvReport {
+    /// Number of secrets written (or that would be, for a dry run)
+    pub written: usize,
+    /// Requested keys not found in the vault
+    pub missing: Vec<String>,
+    /// Set for dry runs, which write nothing
+    pub diff: Option<EnvDiff>,
+    /// Whether the file is in a git working tree and ignored there
+    pub git: GitCheck,
+    /// Every variable requested, in file order
+    pub mappings: Vec<KeyMapping>,
+}
+
+/// State of a write queued for the user's approval
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApprovalStatus {
+    Pending,
+    Approved,
+    Denied,
+    /// Not decided in time, which counts as a denial
+    Expired,
+}
+
+impl ApprovalStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            ApprovalStatus::Pending => "pending",
+            ApprovalStatus::Approved => "approved",
+            ApprovalStatus::Denied => "denied",
+            ApprovalStatus::Expired => "expired",
+        }
+    }
+
+    fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "pending" => Ok(ApprovalStatus::Pending),
+            "approved" => Ok(ApprovalStatus::Approved),
+            "denied" => Ok(ApprovalStatus::Denied),
+            "expired" => Ok(ApprovalStatus::Expired),
+            _ => Err(format!("Invalid approval status: {}", value)),
+        }
+    }
+}
+
+/// Write requested by an MCP client, waiting for the user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WriteApproval {
+    pub id: String,
+    /// Client that asked, as it named itself
+    pub client: String,
+    /// MCP tool called, e.g. `write_env`
+    pub tool: String,
+    /// Secrets, or template variables, that would be written
+    pub keys: Vec<String>,
+    /// Tag whose secrets would be written as well
+    pub tag: Option<String>,
+    /// Resolved target file
+    pub path: String,
+    pub project: Option<String>,
+    /// Environment whose values would be written instead of the defaults
+    pub environment: Option<String>,
+    /// Template read by `fill_env_from_template`
+    pub template: Option<String>,
+    pub mode: WriteMode,
+    /// Format of the file, inferred from its name if the client gave none
+    pub format: OutputFormat,
+    pub created_at: i64,
+}
+
+/// What an "always allow" decision covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApprovalScope {
+    /// Every write for the request's project
+    Project,
+    /// Every write to the request's file
+    Path,
 }
 
+/// Standing approval for MCP writes, for a project or a file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApprovalRule {
+    pub id: i64,
+    pub project_id: Option<String>,
+    /// Name of `project_id`, for display
+    pub project: Option<String>,
+    pub path: Option<String>,
+    pub created_at: i64,
+}
+
+/// Who performed an audited operation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Actor {
+    /// The user, in the desktop app
+    Desktop,
+    /// An MCP client, by the name it gave
+    Mcp(String),
+}
+
+impl fmt::Display for Actor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Actor::Desktop => write!(f, "desktop"),
+            Actor::Mcp(client) => write!(f, "mcp:{}", client),
+        }
+    }
+}
+
+/// Audit log entry; values are never recorded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub id: i64,
+    pub created_at: i64,
+    /// `desktop`, or `mcp:` and the client name
+    pub actor: String,
+    /// e.g. `get_secret` or `write_env`
+    pub operation: String,
+    /// Names of the secrets involved
+    pub secrets: Vec<String>,
+    /// File written or compared, if any
+    pub path: Option<String>,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Filters for `query_audit_log`; unset fields match everything
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuditFilter {
+    /// Exact actor, or `mcp` for every MCP client
+    pub actor: Option<String>,
+    pub operation: Option<String>,
+    /// Entries involving this secret
+    pub secret: Option<String>,
+    /// Entries for this file, or files under this directory
+    pub path: Option<String>,
+    /// Unix timestamps, inclusive
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+    pub success: Option<bool>,
+    /// Newest entries to return
+    pub limit: Option<u32>,
+}
+
+/// Outcome of checking the audit log's hash chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditVerification {
+    pub valid: bool,
+    /// Entries checked
+    pub entries: usize,
+    /// Entries recorded before the chain existed, which cannot be checked
+    pub unchained: usize,
+    /// First entry whose hash does not match: it was modified or moved, or
+    /// the entry before it was removed
+    pub first_broken: Option<i64>,
+    /// Hash of the newest entry when the chain is intact. A copy kept
+    /// elsewhere shows later whether entries were removed from the end.
+    pub last_hash: Option<String>,
+}
+
+/// Audit log row as stored, without its ID
+struct AuditRecord {
+    created_at: i64,
+    actor: String,
+    operation: String,
+    /// JSON array of secret names
+    secrets: String,
+    path: Option<String>,
+    success: bool,
+    error: Option<String>,
+}
+
+impl AuditRecord {
+    /// SHA-256 over the previous entry's hash and this entry's columns,
+    /// serialized as a compact JSON array, as `JSON.stringify` would, so
+    /// the log can also be checked outside the app
+    fn hash(&self, prev_hash: Option<&str>) -> String {
+        let content = serde_json::json!([
+            prev_hash,
+            self.created_at,
+            self.actor,
+            self.operation,
+            self.secrets,
+            self.path,
+            i64::from(self.success),
+            self.error
+        ]);
+        format!("{:x}", Sha256::digest(content.to_string()))
+    }
+}
+
+/// ID of the default project; its secrets are visible from every project
+pub const GLOBAL_PROJECT_ID: &str = "global";
+
+/// Settings key for the auto-lock idle period
+const AUTO_LOCK_MINUTES: &str = "auto_lock_minutes";
+
+/// Auto-lock idle period used until the user changes it
+const DEFAULT_AUTO_LOCK_MINUTES: u32 = 15;
+
+/// Settings key for how many previous values are kept per secret
+const VERSION_RETENTION: &str = "version_retention";
+
+/// Number of previous values kept per secret until the user changes it
+const DEFAULT_VERSION_RETENTION: u32 = 10;
+
+/// Settings key for how many days secrets stay in the trash
+const TRASH_RETENTION_DAYS: &str = "trash_retention_days";
+
+/// Days secrets stay in the trash until the user changes it
+const DEFAULT_TRASH_RETENTION_DAYS: u32 = 30;
+
+/// Settings key for how many backups are kept per env file
+const ENV_BACKUP_RETENTION: &str = "env_backup_retention";
+
+/// Number of backups kept per env file until the user changes it
+const DEFAULT_ENV_BACKUP_RETENTION: u32 = 10;
+
+/// Settings key for where env files may be written (JSON `PathPolicy`)
+const WRITE_PATH_POLICY: &str = "write_path_policy";
+
+/// Settings key for whether the app serves MCP over HTTP when it starts
+const MCP_HTTP_ENABLED: &str = "mcp_http_enabled";
+
+/// Whether the app serves MCP over HTTP when it starts, until the user
+/// chooses. On, as `secret-mcp-server` only relays to it; it listens on
+/// localhost alone and needs a token that only the user can read.
+pub const DEFAULT_MCP_HTTP_ENABLED: bool = true;
+
+/// Settings key for the ID of the first hashed audit entry, set once by a
+/// migration
+const AUDIT_CHAIN_START: &str = "audit_chain_start";
+
+/// Decided write approvals are deleted after this many seconds
+const APPROVAL_HISTORY_SECS: i64 = 24 * 60 * 60;
+
 /// Global database connection
 static DB: Lazy<Mutex<Option<Connection>>> = Lazy::new(|| Mutex::new(None));
 
-/// Get the database file path
-fn get_db_path() -> PathBuf {
+/// Data directory tests use instead of the platform one
+#[cfg(test)]
+pub static TEST_APP_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Get the application data directory
+fn get_app_dir() -> PathBuf {
+    #[cfg(test)]
+    if let Some(dir) = TEST_APP_DIR.lock().unwrap().clone() {
+        return dir;
+    }
     let app_data = dirs::data_dir().unwrap_or_else(|| PathBuf::from("."));
     let app_dir = app_data.join("secret-mcp");
     std::fs::create_dir_all(&app_dir).ok();
-    app_dir.join("secrets.db")
+    app_dir
+}
+
+/// Get the database file path
+fn get_db_path() -> PathBuf {
+    get_app_dir().join("secrets.db")
+}
+
+/// Get the directory holding encrypted env file backups
+fn get_env_backup_dir() -> PathBuf {
+    let dir = get_app_dir().join("env-backups");
+    std::fs::create_dir_all(&dir).ok();
+    dir
+}
+
+/// Get the file the app publishes its MCP endpoint's URL and token in, for
+/// `secret-mcp-server`
+pub fn get_mcp_http_connection_path() -> PathBuf {
+    get_app_dir().join("mcp-http.json")
+}
+
+/// Get the data key file path used before master passwords existed
+fn get_legacy_key_path() -> PathBuf {
+    get_app_dir().join("secrets.key")
 }
 
 /// Get the database path as a string (for MCP server)
@@ -53,21 +479,17 @@ pub fn get_db_path_string() -> String {
 /// Initialize the database connection
 pub fn init_db() -> Result<(), String> {
     let db_path = get_db_path();
-    let conn = Connection::open(&db_path).map_err(|e| e.to_string())?;
+    let mut conn = Connection::open(&db_path).map_err(|e| e.to_string())?;
+    conn.pragma_update(None, "foreign_keys", true)
+        .map_err(|e| e.to_string())?;
+    // The MCP server and the app share the file and wait on each other's
+    // writes, e.g. for approvals
+    conn.busy_timeout(std::time::Duration::from_secs(5))
+        .map_err(|e| e.to_string())?;
 
-    // Create secrets table
-    conn.execute(
-        "CREATE TABLE IF NOT EXISTS secrets (
-            id TEXT PRIMARY KEY,
-            name TEXT UNIQUE NOT NULL,
-            description TEXT,
-            value TEXT NOT NULL,
-            created_at INTEGER NOT NULL,
-            updated_at INTEGER NOT NULL
-        )",
-        [],
-    )
-    .map_err(|e| e.to_string())?;
+    // Bring the schema up to date
+    migrations::migrate(&mut conn)?;
+    purge_expired_trash(&conn)?;
 
     // Store connection globally
     let mut db = DB.lock().map_err(|e| e.to_string())?;
@@ -76,6 +498,114 @@ pub fn init_db() -> Result<(), String> {
     Ok(())
 }
 
+/// Get the vault lock state
+pub fn vault_status() -> Result<vault::VaultStatus, String> {
+    with_db(vault::status)
+}
+
+/// Unlock the vault (setting the master password on first use)
+pub fn unlock_vault(password: &str) -> Result<vault::VaultStatus, String> {
+    with_db(|conn| {
+        vault::unlock(conn, password, &get_legacy_key_path())?;
+        encrypt_plaintext_values(conn)?;
+        vault::status(conn)
+    })
+}
+
+/// Lock the vault, dropping the data key from memory
+pub fn lock_vault() -> Result<vault::VaultStatus, String> {
+    vault::lock()?;
+    with_db(vault::status)
+}
+
+/// Read a setting, if it has been set
+fn get_setting(conn: &Connection, key: &str) -> Result<Option<String>, String> {
+    conn.query_row(
+        "SELECT value FROM settings WHERE key = ?",
+        params![key],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(|e| e.to_string())
+}
+
+/// Insert or replace a setting
+fn set_setting(conn: &Connection, key: &str, value: &str) -> Result<(), String> {
+    conn.execute(
+        "INSERT INTO settings (key, value) VALUES (?, ?)
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        params![key, value],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Get the idle period in minutes after which the vault locks (0 = never)
+pub fn get_auto_lock_minutes() -> Result<u32, String> {
+    with_db(|conn| match get_setting(conn, AUTO_LOCK_MINUTES)? {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("Invalid {} setting", AUTO_LOCK_MINUTES)),
+        None => Ok(DEFAULT_AUTO_LOCK_MINUTES),
+    })
+}
+
+/// Set the idle period in minutes after which the vault locks (0 = never)
+pub fn set_auto_lock_minutes(minutes: u32) -> Result<(), String> {
+    with_db(|conn| set_setting(conn, AUTO_LOCK_MINUTES, &minutes.to_string()))
+}
+
+/// Get whether the app serves MCP over HTTP when it starts
+pub fn get_mcp_http_enabled() -> Result<bool, String> {
+    with_db(|conn| match get_setting(conn, MCP_HTTP_ENABLED)? {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("Invalid {} setting", MCP_HTTP_ENABLED)),
+        None => Ok(DEFAULT_MCP_HTTP_ENABLED),
+    })
+}
+
+/// Set whether the app serves MCP over HTTP when it starts
+pub fn set_mcp_http_enabled(enabled: bool) -> Result<(), String> {
+    with_db(|conn| set_setting(conn, MCP_HTTP_ENABLED, &enabled.to_string()))
+}
+
+/// One-time migration: encrypt values stored as plaintext by older versions.
+/// Encrypted values are stored as BLOBs, so plaintext rows are the TEXT ones.
+fn encrypt_plaintext_values(conn: &Connection) -> Result<(), String> {
+    let mut stmt = conn
+        .prepare("SELECT id, value FROM secrets WHERE typeof(value) = 'text'")
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    if rows.is_empty() {
+        return Ok(());
+    }
+
+    // Zero the pages the plaintext is freed from instead of leaving it there
+    conn.pragma_update(None, "secure_delete", true)
+        .map_err(|e| e.to_string())?;
+    let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+    for (id, value) in rows {
+        let encrypted = encrypt_value(&value)?;
+        tx.execute(
+            "UPDATE secrets SET value = ? WHERE id = ?",
+            params![encrypted, id],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    tx.commit().map_err(|e| e.to_string())?;
+
+    // Rebuild the file so no page written before the migration survives
+    conn.execute_batch("VACUUM").map_err(|e| e.to_string())
+}
+
 /// Helper to get database connection
 fn with_db<T, F: FnOnce(&Connection) -> Result<T, String>>(f: F) -> Result<T, String> {
     let db = DB.lock().map_err(|e| e.to_string())?;
@@ -83,21 +613,39 @@ fn with_db<T, F: FnOnce(&Connection) -> Result<T, String>>(f: F) -> Result<T, St
     f(conn)
 }
 
-/// List all secrets (values masked)
-pub fn list_secrets() -> Result<Vec<SecretInfo>, String> {
+/// Encrypt a value with the vault data key
+fn encrypt_value(value: &str) -> Result<Vec<u8>, String> {
+    vault::with_key(|key| crypto::encrypt(key, value))
+}
+
+/// Decrypt a value stored in the database
+fn decrypt_value(blob: &[u8]) -> Result<String, String> {
+    vault::with_key(|key| crypto::decrypt(key, blob))
+}
+
+/// List all secrets, optionally only those of one project (values masked)
+pub fn list_secrets(project_id: Option<&str>) -> Result<Vec<SecretInfo>, String> {
     with_db(|conn| {
         let mut stmt = conn
-            .prepare("SELECT id, name, description, created_at, updated_at FROM secrets ORDER BY name")
+            .prepare(
+                "SELECT id, project_id, name, description, created_at, updated_at FROM secrets
+                 WHERE deleted_at IS NULL AND (?1 IS NULL OR project_id = ?1)
+                 ORDER BY name",
+            )
             .map_err(|e| e.to_string())?;
+        let mut tags = tags_by_secret(conn)?;
 
         let secrets = stmt
-            .query_map([], |row| {
+            .query_map(params![project_id], |row| {
+                let id: String = row.get(0)?;
                 Ok(SecretInfo {
-                    id: row.get(0)?,
-                    name: row.get(1)?,
-                    description: row.get(2)?,
-                    created_at: row.get(3)?,
-                    updated_at: row.get(4)?,
+                    tags: tags.remove(&id).unwrap_or_default(),
+                    id,
+                    project_id: row.get(1)?,
+                    name: row.get(2)?,
+                    description: row.get(3)?,
+                    created_at: row.get(4)?,
+                    updated_at: row.get(5)?,
                 })
             })
             .map_err(|e| e.to_string())?
@@ -109,45 +657,104 @@ pub fn list_secrets() -> Result<Vec<SecretInfo>, String> {
 }
 
 /// Get a single secret by ID (includes value)
-pub fn get_secret(id: &str) -> Result<Option<Secret>, String> {
-    with_db(|conn| {
-        let mut stmt = conn
-            .prepare("SELECT id, name, description, value, created_at, updated_at FROM secrets WHERE id = ?")
-            .map_err(|e| e.to_string())?;
+pub fn get_secret(actor: &Actor, id: &str) -> Result<Option<Secret>, String> {
+    let result = vault::ensure_unlocked().and_then(|()| with_db(|conn| read_secret(conn, id)));
 
-        let mut rows = stmt.query(params![id]).map_err(|e| e.to_string())?;
+    let secrets: Vec<String> = match &result {
+        Ok(secret) => secret.iter().map(|s| s.name.clone()).collect(),
+        Err(_) => secret_name(id)?.into_iter().collect(),
+    };
+    audit(actor, "get_secret", &secrets, None, &result);
+    result
+}
 
-        if let Some(row) = rows.next().map_err(|e| e.to_string())? {
-            Ok(Some(Secret {
-                id: row.get(0).map_err(|e| e.to_string())?,
-                name: row.get(1).map_err(|e| e.to_string())?,
-                description: row.get(2).map_err(|e| e.to_string())?,
-                value: row.get(3).map_err(|e| e.to_string())?,
-                created_at: row.get(4).map_err(|e| e.to_string())?,
-                updated_at: row.get(5).map_err(|e| e.to_string())?,
-            }))
-        } else {
-            Ok(None)
-        }
+/// Name of a secret, for the audit log
+fn secret_name(id: &str) -> Result<Option<String>, String> {
+    with_db(|conn| {
+        conn.query_row(
+            "SELECT name FROM secrets WHERE id = ?",
+            params![id],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| e.to_string())
     })
 }
 
-/// Create a new secret
-pub fn create_secret(name: &str, description: Option<&str>, value: &str) -> Result<Secret, String> {
+/// Read and decrypt a secret using an already-held connection
+fn read_secret(conn: &Connection, id: &str) -> Result<Option<Secret>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, project_id, name, description, value, created_at, updated_at FROM secrets
+             WHERE id = ? AND deleted_at IS NULL",
+        )
+        .map_err(|e| e.to_string())?;
+
+    let mut rows = stmt.query(params![id]).map_err(|e| e.to_string())?;
+
+    if let Some(row) = rows.next().map_err(|e| e.to_string())? {
+        let encrypted: Vec<u8> = row.get(4).map_err(|e| e.to_string())?;
+        Ok(Some(Secret {
+            id: id.to_string(),
+            tags: load_tags(conn, id)?,
+            project_id: row.get(1).map_err(|e| e.to_string())?,
+            name: row.get(2).map_err(|e| e.to_string())?,
+            description: row.get(3).map_err(|e| e.to_string())?,
+            value: decrypt_value(&encrypted)?,
+            created_at: row.get(5).map_err(|e| e.to_string())?,
+            updated_at: row.get(6).map_err(|e| e.to_string())?,
+        }))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Create a new secret in a project (the global project if None)
+pub fn create_secret(
+    actor: &Actor,
+    name: &str,
+    description: Option<&str>,
+    value: &str,
+    project_id: Option<&str>,
+    tags: &[String],
+) -> Result<Secret, String> {
+    let result = insert_secret(name, description, value, project_id, tags);
+    audit(actor, "create_secret", &[name.to_string()], None, &result);
+    result
+}
+
+fn insert_secret(
+    name: &str,
+    description: Option<&str>,
+    value: &str,
+    project_id: Option<&str>,
+    tags: &[String],
+) -> Result<Secret, String> {
     let id = Uuid::new_v4().to_string();
     let now = Utc::now().timestamp();
+    let encrypted = encrypt_value(value)?;
+    let project_id = project_id.unwrap_or(GLOBAL_PROJECT_ID);
+    let tags = normalize_tags(tags)?;
 
     with_db(|conn| {
-        conn.execute(
-            "INSERT INTO secrets (id, name, description, value, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?)",
-            params![id, name, description, value, now, now],
+        ensure_project_exists(conn, project_id)?;
+        ensure_name_not_in_trash(conn, project_id, name)?;
+
+        let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+        tx.execute(
+            "INSERT INTO secrets (id, project_id, name, description, value, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
+            params![id, project_id, name, description, encrypted, now, now],
         )
         .map_err(|e| e.to_string())?;
+        set_tags(&tx, &id, &tags)?;
+        tx.commit().map_err(|e| e.to_string())?;
 
         Ok(Secret {
             id,
+            project_id: project_id.to_string(),
             name: name.to_string(),
             description: description.map(|s| s.to_string()),
+            tags,
             value: value.to_string(),
             created_at: now,
             updated_at: now,
@@ -155,39 +762,87 @@ pub fn create_secret(name: &str, description: Option<&str>, value: &str) -> Resu
     })
 }
 
-/// Update an existing secret
+/// Update an existing secret; `project_id` moves it to another project and
+/// `tags` replaces its tags (both are kept if None)
 pub fn update_secret(
+    actor: &Actor,
+    id: &str,
+    name: &str,
+    description: Option<&str>,
+    value: &str,
+    project_id: Option<&str>,
+    tags: Option<&[String]>,
+) -> Result<Secret, String> {
+    let result = rewrite_secret(id, name, description, value, project_id, tags);
+    audit(actor, "update_secret", &[name.to_string()], None, &result);
+    result
+}
+
+fn rewrite_secret(
     id: &str,
     name: &str,
     description: Option<&str>,
     value: &str,
+    project_id: Option<&str>,
+    tags: Option<&[String]>,
 ) -> Result<Secret, String> {
     let now = Utc::now().timestamp();
+    let encrypted = encrypt_value(value)?;
+    let tags = tags.map(normalize_tags).transpose()?;
 
     with_db(|conn| {
-        let rows_affected = conn
-            .execute(
-                "UPDATE secrets SET name = ?, description = ?, value = ?, updated_at = ? WHERE id = ?",
-                params![name, description, value, now, id],
+        let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+
+        // Keep the previous value in history if it is changing
+        let previous: Option<(String, Vec<u8>)> = tx
+            .query_row(
+                "SELECT project_id, value FROM secrets WHERE id = ? AND deleted_at IS NULL",
+                params![id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
             )
+            .optional()
             .map_err(|e| e.to_string())?;
-
-        if rows_affected == 0 {
-            return Err("Secret not found".to_string());
+        let (current_project_id, previous) = previous.ok_or("Secret not found")?;
+        if decrypt_value(&previous)? != value {
+            archive_version(&tx, id, None, &previous, now)?;
         }
 
+        let project_id = project_id.unwrap_or(&current_project_id);
+        ensure_project_exists(&tx, project_id)?;
+        ensure_name_not_in_trash(&tx, project_id, name)?;
+
+        tx.execute(
+            "UPDATE secrets SET project_id = ?, name = ?, description = ?, value = ?, updated_at = ?
+             WHERE id = ? AND deleted_at IS NULL",
+            params![project_id, name, description, encrypted, now, id],
+        )
+        .map_err(|e| e.to_string())?;
+
+        let tags = match tags {
+            Some(tags) => {
+                set_tags(&tx, id, &tags)?;
+                tags
+            }
+            None => load_tags(&tx, id)?,
+        };
+
         // Get created_at from existing record
-        let mut stmt = conn
-            .prepare("SELECT created_at FROM secrets WHERE id = ?")
-            .map_err(|e| e.to_string())?;
-        let created_at: i64 = stmt
-            .query_row(params![id], |row| row.get(0))
+        let created_at: i64 = tx
+            .query_row(
+                "SELECT created_at FROM secrets WHERE id = ?",
+                params![id],
+                |row| row.get(0),
+            )
             .map_err(|e| e.to_string())?;
 
+        tx.commit().map_err(|e| e.to_string())?;
+
         Ok(Secret {
             id: id.to_string(),
+            project_id: project_id.to_string(),
             name: name.to_string(),
             description: description.map(|s| s.to_string()),
+            tags,
             value: value.to_string(),
             created_at,
             updated_at: now,
@@ -195,104 +850,3250 @@ pub fn update_secret(
     })
 }
 
-/// Delete a secret
-pub fn delete_secret(id: &str) -> Result<bool, String> {
-    with_db(|conn| {
-        let rows_affected = conn
-            .execute("DELETE FROM secrets WHERE id = ?", params![id])
-            .map_err(|e| e.to_string())?;
+/// Trim and lowercase tags, dropping duplicates. Tags cannot contain
+/// whitespace or commas so they survive `tag:` filters and comma-separated input.
+fn normalize_tags(tags: &[String]) -> Result<Vec<String>, String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag.trim().to_lowercase();
+        if tag.is_empty() {
+            continue;
+        }
+        if tag.contains(|c: char| c.is_whitespace() || c == ',') {
+            return Err(format!(
+                "Invalid tag \"{}\": tags cannot contain spaces or commas",
+                tag
+            ));
+        }
+        if !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    normalized.sort();
+    Ok(normalized)
+}
 
-        Ok(rows_affected > 0)
-    })
+/// Normalize a single tag, such as the one `write_env` selects secrets by
+pub fn normalize_tag(tag: &str) -> Result<String, String> {
+    normalize_tags(&[tag.to_string()])?
+        .pop()
+        .ok_or_else(|| "Tag cannot be empty".to_string())
 }
 
-/// Search secrets by name or description (fuzzy match)
-pub fn search_secrets(query: &str) -> Result<Vec<SecretSearchResult>, String> {
-    with_db(|conn| {
-        let pattern = format!("%{}%", query.to_lowercase());
+/// Replace a secret's tags, creating new tags and dropping unused ones
+fn set_tags(conn: &Connection, secret_id: &str, tags: &[String]) -> Result<(), String> {
+    conn.execute(
+        "DELETE FROM secret_tags WHERE secret_id = ?",
+        params![secret_id],
+    )
+    .map_err(|e| e.to_string())?;
 
-        let mut stmt = conn
-            .prepare(
-                "SELECT name, description FROM secrets
-                 WHERE LOWER(name) LIKE ? OR LOWER(COALESCE(description, '')) LIKE ?
-                 ORDER BY name",
-            )
+    for tag in tags {
+        conn.execute("INSERT OR IGNORE INTO tags (name) VALUES (?)", params![tag])
             .map_err(|e| e.to_string())?;
+        conn.execute(
+            "INSERT INTO secret_tags (secret_id, tag_id) SELECT ?, id FROM tags WHERE name = ?",
+            params![secret_id, tag],
+        )
+        .map_err(|e| e.to_string())?;
+    }
 
-        let results = stmt
-            .query_map(params![&pattern, &pattern], |row| {
-                Ok(SecretSearchResult {
-                    name: row.get(0)?,
-                    description: row.get(1)?,
-                })
-            })
-            .map_err(|e| e.to_string())?
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| e.to_string())?;
+    conn.execute(
+        "DELETE FROM tags WHERE id NOT IN (SELECT tag_id FROM secret_tags)",
+        [],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
 
-        Ok(results)
+/// Get the tags of one secret, sorted by name
+fn load_tags(conn: &Connection, secret_id: &str) -> Result<Vec<String>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT t.name FROM secret_tags st JOIN tags t ON t.id = st.tag_id
+             WHERE st.secret_id = ? ORDER BY t.name",
+        )
+        .map_err(|e| e.to_string())?;
+
+    let tags = stmt
+        .query_map(params![secret_id], |row| row.get(0))
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    Ok(tags)
+}
+
+/// Get the tags of every secret, keyed by secret ID
+fn tags_by_secret(conn: &Connection) -> Result<HashMap<String, Vec<String>>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT st.secret_id, t.name FROM secret_tags st JOIN tags t ON t.id = st.tag_id
+             ORDER BY t.name",
+        )
+        .map_err(|e| e.to_string())?;
+
+    let mut tags: HashMap<String, Vec<String>> = HashMap::new();
+    let rows = stmt
+        .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get(1)?)))
+        .map_err(|e| e.to_string())?;
+    for row in rows {
+        let (secret_id, tag) = row.map_err(|e| e.to_string())?;
+        tags.entry(secret_id).or_default().push(tag);
+    }
+    Ok(tags)
+}
+
+/// List every tag in use
+pub fn list_tags() -> Result<Vec<String>, String> {
+    with_db(|conn| {
+        let mut stmt = conn
+            .prepare("SELECT name FROM tags ORDER BY name")
+            .map_err(|e| e.to_string())?;
+
+        let tags = stmt
+            .query_map([], |row| row.get(0))
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        Ok(tags)
     })
 }
 
-/// Get secret values by names (for writing .env files)
-pub fn get_values_by_names(names: &[String]) -> Result<Vec<(String, String)>, String> {
+/// Record a superseded value, the default or one environment's, in the
+/// secret's history and prune old versions
+fn archive_version(
+    conn: &Connection,
+    secret_id: &str,
+    environment: Option<&str>,
+    value: &[u8],
+    now: i64,
+) -> Result<(), String> {
+    conn.execute(
+        "INSERT INTO secret_versions (secret_id, environment, value, replaced_at) VALUES (?, ?, ?, ?)",
+        params![secret_id, environment, value, now],
+    )
+    .map_err(|e| e.to_string())?;
+
+    let retention = match get_setting(conn, VERSION_RETENTION)? {
+        Some(value) => value
+            .parse::<u32>()
+            .map_err(|_| format!("Invalid {} setting", VERSION_RETENTION))?,
+        None => DEFAULT_VERSION_RETENTION,
+    };
+    conn.execute(
+        "DELETE FROM secret_versions WHERE secret_id = ? AND id NOT IN (
+            SELECT id FROM secret_versions WHERE secret_id = ? ORDER BY id DESC LIMIT ?
+        )",
+        params![secret_id, secret_id, retention],
+    )
+    .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// List previous values of a secret, newest first (values masked)
+pub fn list_secret_versions(secret_id: &str) -> Result<Vec<SecretVersionInfo>, String> {
     with_db(|conn| {
-        let mut results = Vec::new();
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, secret_id, environment, replaced_at FROM secret_versions
+                 WHERE secret_id = ? ORDER BY id DESC",
+            )
+            .map_err(|e| e.to_string())?;
 
-        for name in names {
-            let mut stmt = conn
-                .prepare("SELECT name, value FROM secrets WHERE name = ?")
-                .map_err(|e| e.to_string())?;
+        let versions = stmt
+            .query_map(params![secret_id], |row| {
+                Ok(SecretVersionInfo {
+                    id: row.get(0)?,
+                    secret_id: row.get(1)?,
+                    environment: row.get(2)?,
+                    replaced_at: row.get(3)?,
+                })
+            })
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+
+        Ok(versions)
+    })
+}
+
+/// Restore a previous value, to the default or the environment it came
+/// from; the current value is kept in history
+pub fn restore_secret_version(actor: &Actor, version_id: i64) -> Result<Secret, String> {
+    let result = restore_version(version_id);
+
+    let secrets: Vec<String> = match &result {
+        Ok(secret) => vec![secret.name.clone()],
+        Err(_) => with_db(|conn| {
+            conn.query_row(
+                "SELECT s.name FROM secret_versions v JOIN secrets s ON s.id = v.secret_id
+                 WHERE v.id = ?",
+                params![version_id],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| e.to_string())
+        })?
+        .into_iter()
+        .collect(),
+    };
+    audit(actor, "restore_secret_version", &secrets, None, &result);
+    result
+}
+
+fn restore_version(version_id: i64) -> Result<Secret, String> {
+    vault::ensure_unlocked()?;
+    let now = Utc::now().timestamp();
+
+    with_db(|conn| {
+        let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
 
-            if let Ok(value) = stmt.query_row(params![name], |row| {
-                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
-            }) {
-                results.push(value);
+        let (secret_id, environment, restored): (String, Option<String>, Vec<u8>) = tx
+            .query_row(
+                "SELECT secret_id, environment, value FROM secret_versions WHERE id = ?",
+                params![version_id],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()
+            .map_err(|e| e.to_string())?
+            .ok_or("Version not found")?;
+
+        let current: Vec<u8> = tx
+            .query_row(
+                "SELECT value FROM secrets WHERE id = ? AND deleted_at IS NULL",
+                params![secret_id],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| e.to_string())?
+            .ok_or("Secret not found")?;
+
+        match environment.as_deref() {
+            None => {
+                archive_version(&tx, &secret_id, None, &current, now)?;
+                tx.execute(
+                    "UPDATE secrets SET value = ?, updated_at = ? WHERE id = ?",
+                    params![restored, now, secret_id],
+                )
+                .map_err(|e| e.to_string())?;
+            }
+            Some(environment) => {
+                upsert_environment_value(&tx, &secret_id, environment, &restored, now)?;
             }
         }
 
-        Ok(results)
+        let secret = read_secret(&tx, &secret_id)?.ok_or("Secret not found")?;
+        tx.commit().map_err(|e| e.to_string())?;
+        Ok(secret)
     })
 }
 
-/// Write secrets to a .env file
-pub fn write_env_file(keys: &[String], path: &str) -> Result<(usize, Vec<String>), String> {
-    // Validate path - must be absolute
-    let path = std::path::Path::new(path);
-    if !path.is_absolute() {
-        return Err("Path must be absolute".to_string());
+/// Get how many previous values are kept per secret
+pub fn get_version_retention() -> Result<u32, String> {
+    with_db(|conn| match get_setting(conn, VERSION_RETENTION)? {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("Invalid {} setting", VERSION_RETENTION)),
+        None => Ok(DEFAULT_VERSION_RETENTION),
+    })
+}
+
+/// Set how many previous values are kept per secret
+pub fn set_version_retention(versions: u32) -> Result<(), String> {
+    with_db(|conn| set_setting(conn, VERSION_RETENTION, &versions.to_string()))
+}
+
+/// Names stay unique across the trash, so point the user at the trashed secret
+fn ensure_name_not_in_trash(conn: &Connection, project_id: &str, name: &str) -> Result<(), String> {
+    let trashed: bool = conn
+        .query_row(
+            "SELECT EXISTS(SELECT 1 FROM secrets
+                WHERE project_id = ? AND name = ? AND deleted_at IS NOT NULL)",
+            params![project_id, name],
+            |row| row.get(0),
+        )
+        .map_err(|e| e.to_string())?;
+
+    if trashed {
+        return Err(format!(
+            "A secret named {} is in the trash. Restore or purge it first.",
+            name
+        ));
     }
+    Ok(())
+}
 
-    // Get values
-    let values = get_values_by_names(keys)?;
-    let found_names: std::collections::HashSet<_> = values.iter().map(|(n, _)| n.clone()).collect();
+/// Move a secret to the trash
+pub fn delete_secret(actor: &Actor, id: &str) -> Result<bool, String> {
+    let name = secret_name(id)?;
+    let now = Utc::now().timestamp();
 
-    // Find missing keys
-    let missing: Vec<String> = keys
-        .iter()
-        .filter(|k| !found_names.contains(*k))
-        .cloned()
-        .collect();
+    let result = with_db(|conn| {
+        let rows_affected = conn
+            .execute(
+                "UPDATE secrets SET deleted_at = ? WHERE id = ? AND deleted_at IS NULL",
+                params![now, id],
+            )
+            .map_err(|e| e.to_string())?;
 
-    // Build .env content
-    let content: String = values
-        .iter()
-        .map(|(name, value)| {
-            // Escape value if needed
-            if value.contains(' ')
-                || value.contains('"')
-                || value.contains('\'')
-                || value.contains('\n')
-            {
-                format!("{}=\"{}\"\n", name, value.replace('"', "\\\""))
-            } else {
-                format!("{}={}\n", name, value)
-            }
+        purge_expired_trash(conn)?;
+        Ok(rows_affected > 0)
+    });
+    let secrets: Vec<String> = name.into_iter().collect();
+    audit(actor, "delete_secret", &secrets, None, &result);
+    result
+}
+
+/// List secrets in the trash, most recently deleted first
+pub fn list_trash() -> Result<Vec<TrashedSecretInfo>, String> {
+    with_db(|conn| {
+        purge_expired_trash(conn)?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, project_id, name, description, deleted_at FROM secrets
+                 WHERE deleted_at IS NOT NULL ORDER BY deleted_at DESC",
+            )
+            .map_err(|e| e.to_string())?;
+
+        let secrets = stmt
+            .query_map([], |row| {
+                Ok(TrashedSecretInfo {
+                    id: row.get(0)?,
+                    project_id: row.get(1)?,
+                    name: row.get(2)?,
+                    description: row.get(3)?,
+                    deleted_at: row.get(4)?,
+                })
+            })
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+
+        Ok(secrets)
+    })
+}
+
+/// Move a secret out of the trash
+pub fn restore_secret(actor: &Actor, id: &str) -> Result<bool, String> {
+    let name = secret_name(id)?;
+    let result = with_db(|conn| {
+        let rows_affected = conn
+            .execute(
+                "UPDATE secrets SET deleted_at = NULL WHERE id = ? AND deleted_at IS NOT NULL",
+                params![id],
+            )
+            .map_err(|e| e.to_string())?;
+
+        Ok(rows_affected > 0)
+    });
+    let secrets: Vec<String> = name.into_iter().collect();
+    audit(actor, "restore_secret", &secrets, None, &result);
+    result
+}
+
+/// Permanently delete one trashed secret, or the whole trash if `id` is None
+pub fn purge_trash(actor: &Actor, id: Option<&str>) -> Result<usize, String> {
+    let mut names = Vec::new();
+    let result = with_db(|conn| {
+        let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+        let mut stmt = tx
+            .prepare(
+                "SELECT name FROM secrets
+                 WHERE (?1 IS NULL OR id = ?1) AND deleted_at IS NOT NULL ORDER BY name",
+            )
+            .map_err(|e| e.to_string())?;
+        names = stmt
+            .query_map(params![id], |row| row.get(0))
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<String>, _>>()
+            .map_err(|e| e.to_string())?;
+        drop(stmt);
+
+        let rows_affected = tx
+            .execute(
+                "DELETE FROM secrets WHERE (?1 IS NULL OR id = ?1) AND deleted_at IS NOT NULL",
+                params![id],
+            )
+            .map_err(|e| e.to_string())?;
+        tx.commit().map_err(|e| e.to_string())?;
+        Ok(rows_affected)
+    });
+    audit(actor, "purge_trash", &names, None, &result);
+    result
+}
+
+/// Permanently delete secrets that have been in the trash longer than the retention period
+fn purge_expired_trash(conn: &Connection) -> Result<(), String> {
+    let days = match get_setting(conn, TRASH_RETENTION_DAYS)? {
+        Some(value) => value
+            .parse::<u32>()
+            .map_err(|_| format!("Invalid {} setting", TRASH_RETENTION_DAYS))?,
+        None => DEFAULT_TRASH_RETENTION_DAYS,
+    };
+    if days == 0 {
+        return Ok(());
+    }
+
+    let cutoff = Utc::now().timestamp() - i64::from(days) * 24 * 60 * 60;
+    conn.execute(
+        "DELETE FROM secrets WHERE deleted_at IS NOT NULL AND deleted_at < ?",
+        params![cutoff],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Get how many days secrets stay in the trash (0 = until purged manually)
+pub fn get_trash_retention_days() -> Result<u32, String> {
+    with_db(|conn| match get_setting(conn, TRASH_RETENTION_DAYS)? {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("Invalid {} setting", TRASH_RETENTION_DAYS)),
+        None => Ok(DEFAULT_TRASH_RETENTION_DAYS),
+    })
+}
+
+/// Set how many days secrets stay in the trash (0 = until purged manually)
+pub fn set_trash_retention_days(days: u32) -> Result<(), String> {
+    with_db(|conn| set_setting(conn, TRASH_RETENTION_DAYS, &days.to_string()))
+}
+
+/// Find a project by ID or name
+fn resolve_project_id(conn: &Connection, project: &str) -> Result<String, String> {
+    conn.query_row(
+        "SELECT id FROM projects WHERE id = ?1 OR name = ?1",
+        params![project],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(|e| e.to_string())?
+    .ok_or_else(|| format!("Project not found: {}", project))
+}
+
+fn ensure_project_exists(conn: &Connection, project_id: &str) -> Result<(), String> {
+    let exists: bool = conn
+        .query_row(
+            "SELECT EXISTS(SELECT 1 FROM projects WHERE id = ?)",
+            params![project_id],
+            |row| row.get(0),
+        )
+        .map_err(|e| e.to_string())?;
+
+    if !exists {
+        return Err(format!("Project not found: {}", project_id));
+    }
+    Ok(())
+}
+
+/// List all projects, the global project first
+pub fn list_projects() -> Result<Vec<Project>, String> {
+    with_db(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, name, description, created_at, updated_at FROM projects
+                 ORDER BY id != ?, name",
+            )
+            .map_err(|e| e.to_string())?;
+
+        let projects = stmt
+            .query_map(params![GLOBAL_PROJECT_ID], |row| {
+                Ok(Project {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    description: row.get(2)?,
+                    created_at: row.get(3)?,
+                    updated_at: row.get(4)?,
+                })
+            })
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+
+        Ok(projects)
+    })
+}
+
+/// Create a new project
+pub fn create_project(name: &str, description: Option<&str>) -> Result<Project, String> {
+    let id = Uuid::new_v4().to_string();
+    let now = Utc::now().timestamp();
+
+    with_db(|conn| {
+        conn.execute(
+            "INSERT INTO projects (id, name, description, created_at, updated_at) VALUES (?, ?, ?, ?, ?)",
+            params![id, name, description, now, now],
+        )
+        .map_err(|e| e.to_string())?;
+
+        Ok(Project {
+            id,
+            name: name.to_string(),
+            description: description.map(|s| s.to_string()),
+            created_at: now,
+            updated_at: now,
         })
-        .collect();
+    })
+}
+
+/// Rename or re-describe a project
+pub fn update_project(id: &str, name: &str, description: Option<&str>) -> Result<Project, String> {
+    let now = Utc::now().timestamp();
+
+    with_db(|conn| {
+        let rows_affected = conn
+            .execute(
+                "UPDATE projects SET name = ?, description = ?, updated_at = ? WHERE id = ?",
+                params![name, description, now, id],
+            )
+            .map_err(|e| e.to_string())?;
+
+        if rows_affected == 0 {
+            return Err("Project not found".to_string());
+        }
+
+        let created_at: i64 = conn
+            .query_row(
+                "SELECT created_at FROM projects WHERE id = ?",
+                params![id],
+                |row| row.get(0),
+            )
+            .map_err(|e| e.to_string())?;
+
+        Ok(Project {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: description.map(|s| s.to_string()),
+            created_at,
+            updated_at: now,
+        })
+    })
+}
+
+/// Delete an empty project; the global project cannot be deleted
+pub fn delete_project(id: &str) -> Result<bool, String> {
+    if id == GLOBAL_PROJECT_ID {
+        return Err("The global project cannot be deleted".to_string());
+    }
+
+    with_db(|conn| {
+        let has_secrets: bool = conn
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM secrets WHERE project_id = ?)",
+                params![id],
+                |row| row.get(0),
+            )
+            .map_err(|e| e.to_string())?;
+
+        if has_secrets {
+            return Err(
+                "Project still has secrets (including the trash). Move or purge them first."
+                    .to_string(),
+            );
+        }
+
+        let rows_affected = conn
+            .execute("DELETE FROM projects WHERE id = ?", params![id])
+            .map_err(|e| e.to_string())?;
+
+        Ok(rows_affected > 0)
+    })
+}
+
+fn ensure_environment_exists(conn: &Connection, environment: &str) -> Result<(), String> {
+    let exists: bool = conn
+        .query_row(
+            "SELECT EXISTS(SELECT 1 FROM environments WHERE name = ?)",
+            params![environment],
+            |row| row.get(0),
+        )
+        .map_err(|e| e.to_string())?;
+
+    if !exists {
+        return Err(format!("Environment not found: {}", environment));
+    }
+    Ok(())
+}
+
+/// List all environments
+pub fn list_environments() -> Result<Vec<Environment>, String> {
+    with_db(|conn| {
+        let mut stmt = conn
+            .prepare("SELECT name, created_at FROM environments ORDER BY created_at, name")
+            .map_err(|e| e.to_string())?;
+
+        let environments = stmt
+            .query_map([], |row| {
+                Ok(Environment {
+                    name: row.get(0)?,
+                    created_at: row.get(1)?,
+                })
+            })
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
 
-    // Write file
-    std::fs::write(path, content).map_err(|e| e.to_string())?;
+        Ok(environments)
+    })
+}
 
-    Ok((values.len(), missing))
+/// Create a new environment
+pub fn create_environment(name: &str) -> Result<Environment, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Environment name cannot be empty".to_string());
+    }
+    let now = Utc::now().timestamp();
+
+    with_db(|conn| {
+        conn.execute(
+            "INSERT INTO environments (name, created_at) VALUES (?, ?)",
+            params![name, now],
+        )
+        .map_err(|e| e.to_string())?;
+
+        Ok(Environment {
+            name: name.to_string(),
+            created_at: now,
+        })
+    })
+}
+
+/// Rename an environment, keeping its values
+pub fn rename_environment(name: &str, new_name: &str) -> Result<bool, String> {
+    let new_name = new_name.trim();
+    if new_name.is_empty() {
+        return Err("Environment name cannot be empty".to_string());
+    }
+
+    with_db(|conn| {
+        let rows_affected = conn
+            .execute(
+                "UPDATE environments SET name = ? WHERE name = ?",
+                params![new_name, name],
+            )
+            .map_err(|e| e.to_string())?;
+
+        Ok(rows_affected > 0)
+    })
+}
+
+/// Delete an environment that no secret has a value for
+pub fn delete_environment(name: &str) -> Result<bool, String> {
+    with_db(|conn| {
+        let in_use: bool = conn
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM secret_environment_values WHERE environment = ?)",
+                params![name],
+                |row| row.get(0),
+            )
+            .map_err(|e| e.to_string())?;
+
+        if in_use {
+            return Err("Environment still has secret values. Clear them first.".to_string());
+        }
+
+        let rows_affected = conn
+            .execute("DELETE FROM environments WHERE name = ?", params![name])
+            .map_err(|e| e.to_string())?;
+
+        Ok(rows_affected > 0)
+    })
+}
+
+/// List the per-environment values of a secret
+pub fn list_secret_environment_values(
+    actor: &Actor,
+    secret_id: &str,
+) -> Result<Vec<SecretEnvironmentValue>, String> {
+    let result = vault::ensure_unlocked().and_then(|()| read_environment_values(secret_id));
+
+    let secrets: Vec<String> = secret_name(secret_id)?.into_iter().collect();
+    audit(
+        actor,
+        "list_secret_environment_values",
+        &secrets,
+        None,
+        &result,
+    );
+    result
+}
+
+fn read_environment_values(secret_id: &str) -> Result<Vec<SecretEnvironmentValue>, String> {
+    with_db(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT v.environment, v.value, v.updated_at FROM secret_environment_values v
+                 JOIN environments e ON e.name = v.environment
+                 WHERE v.secret_id = ? ORDER BY e.created_at, e.name",
+            )
+            .map_err(|e| e.to_string())?;
+
+        let rows = stmt
+            .query_map(params![secret_id], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, Vec<u8>>(1)?,
+                    row.get::<_, i64>(2)?,
+                ))
+            })
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+
+        rows.into_iter()
+            .map(|(environment, encrypted, updated_at)| {
+                Ok(SecretEnvironmentValue {
+                    environment,
+                    value: decrypt_value(&encrypted)?,
+                    updated_at,
+                })
+            })
+            .collect()
+    })
+}
+
+/// Set the value a secret takes in one environment
+pub fn set_secret_environment_value(
+    actor: &Actor,
+    secret_id: &str,
+    environment: &str,
+    value: &str,
+) -> Result<(), String> {
+    let result = write_environment_value(secret_id, environment, value);
+
+    let secrets: Vec<String> = secret_name(secret_id)?.into_iter().collect();
+    audit(
+        actor,
+        "set_secret_environment_value",
+        &secrets,
+        None,
+        &result,
+    );
+    result
+}
+
+fn write_environment_value(secret_id: &str, environment: &str, value: &str) -> Result<(), String> {
+    let now = Utc::now().timestamp();
+    let encrypted = encrypt_value(value)?;
+
+    with_db(|conn| {
+        ensure_environment_exists(conn, environment)?;
+
+        let exists: bool = conn
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM secrets WHERE id = ? AND deleted_at IS NULL)",
+                params![secret_id],
+                |row| row.get(0),
+            )
+            .map_err(|e| e.to_string())?;
+        if !exists {
+            return Err("Secret not found".to_string());
+        }
+
+        let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+        upsert_environment_value(&tx, secret_id, environment, &encrypted, now)?;
+        tx.commit().map_err(|e| e.to_string())
+    })
+}
+
+/// Set one environment's value, keeping the value it replaces in history if
+/// it is changing
+fn upsert_environment_value(
+    conn: &Connection,
+    secret_id: &str,
+    environment: &str,
+    encrypted: &[u8],
+    now: i64,
+) -> Result<(), String> {
+    if let Some(previous) = read_environment_value(conn, secret_id, environment)? {
+        if decrypt_value(&previous)? != decrypt_value(encrypted)? {
+            archive_version(conn, secret_id, Some(environment), &previous, now)?;
+        }
+    }
+
+    conn.execute(
+        "INSERT INTO secret_environment_values (secret_id, environment, value, updated_at)
+         VALUES (?, ?, ?, ?)
+         ON CONFLICT(secret_id, environment) DO UPDATE SET value = excluded.value, updated_at = excluded.updated_at",
+        params![secret_id, environment, encrypted, now],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+fn read_environment_value(
+    conn: &Connection,
+    secret_id: &str,
+    environment: &str,
+) -> Result<Option<Vec<u8>>, String> {
+    conn.query_row(
+        "SELECT value FROM secret_environment_values WHERE secret_id = ? AND environment = ?",
+        params![secret_id, environment],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(|e| e.to_string())
+}
+
+/// Remove a secret's value for one environment so it falls back to the
+/// default; the removed value is kept in history
+pub fn clear_secret_environment_value(
+    actor: &Actor,
+    secret_id: &str,
+    environment: &str,
+) -> Result<bool, String> {
+    let result = remove_environment_value(secret_id, environment);
+
+    let secrets: Vec<String> = secret_name(secret_id)?.into_iter().collect();
+    audit(
+        actor,
+        "clear_secret_environment_value",
+        &secrets,
+        None,
+        &result,
+    );
+    result
+}
+
+fn remove_environment_value(secret_id: &str, environment: &str) -> Result<bool, String> {
+    let now = Utc::now().timestamp();
+
+    with_db(|conn| {
+        let tx = conn.unchecked_transaction().map_err(|e| e.to_string())?;
+        let Some(previous) = read_environment_value(&tx, secret_id, environment)? else {
+            return Ok(false);
+        };
+        archive_version(&tx, secret_id, Some(environment), &previous, now)?;
+
+        tx.execute(
+            "DELETE FROM secret_environment_values WHERE secret_id = ? AND environment = ?",
+            params![secret_id, environment],
+        )
+        .map_err(|e| e.to_string())?;
+        tx.commit().map_err(|e| e.to_string())?;
+        Ok(true)
+    })
+}
+
+/// Search secrets by name, description or tag (fuzzy match); `tag:aws`
+/// terms only keep secrets carrying that tag. With a project, only that project's secrets and the global ones it does
+/// not override are returned; without one, every project is searched.
+pub fn search_secrets(
+    query: &str,
+    project: Option<&str>,
+) -> Result<Vec<SecretSearchResult>, String> {
+    // `tag:name` terms filter by tag; the rest is matched as text
+    let (tag_filters, terms): (Vec<&str>, Vec<&str>) = query
+        .split_whitespace()
+        .partition(|term| term.starts_with("tag:"));
+    let tag_filters: Vec<String> = tag_filters
+        .iter()
+        .map(|term| term["tag:".len()..].to_lowercase())
+        .collect();
+
+    with_db(|conn| {
+        let pattern = format!("%{}%", terms.join(" ").to_lowercase());
+        let project_id = match project {
+            Some(project) => Some(resolve_project_id(conn, project)?),
+            None => None,
+        };
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT s.id, s.name, s.description, p.name FROM secrets s
+                 JOIN projects p ON p.id = s.project_id
+                 WHERE s.deleted_at IS NULL
+                   AND (LOWER(s.name) LIKE ?1 OR LOWER(COALESCE(s.description, '')) LIKE ?1
+                       OR EXISTS (
+                           SELECT 1 FROM secret_tags st JOIN tags t ON t.id = st.tag_id
+                           WHERE st.secret_id = s.id AND t.name LIKE ?1
+                       ))
+                   AND (?2 IS NULL OR s.project_id = ?2 OR (s.project_id = ?3 AND NOT EXISTS (
+                       SELECT 1 FROM secrets o
+                       WHERE o.project_id = ?2 AND o.name = s.name AND o.deleted_at IS NULL
+                   )))
+                 ORDER BY s.name, p.name",
+            )
+            .map_err(|e| e.to_string())?;
+        let mut tags = tags_by_secret(conn)?;
+
+        let results = stmt
+            .query_map(params![&pattern, project_id, GLOBAL_PROJECT_ID], |row| {
+                let id: String = row.get(0)?;
+                Ok(SecretSearchResult {
+                    name: row.get(1)?,
+                    description: row.get(2)?,
+                    tags: tags.remove(&id).unwrap_or_default(),
+                    project: row.get(3)?,
+                })
+            })
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+
+        Ok(results
+            .into_iter()
+            .filter(|result| tag_filters.iter().all(|tag| result.tags.contains(tag)))
+            .collect())
+    })
+}
+
+/// Get secret values by names (for writing .env files).
+/// A project's own secret wins over a global one with the same name, and an
+/// environment's value wins over the secret's default value.
+pub fn get_values_by_names(
+    names: &[String],
+    project: Option<&str>,
+    environment: Option<&str>,
+) -> Result<Vec<(String, String)>, String> {
+    vault::ensure_unlocked()?;
+
+    with_db(|conn| {
+        let mut results = Vec::new();
+        let project_id = match project {
+            Some(project) => resolve_project_id(conn, project)?,
+            None => GLOBAL_PROJECT_ID.to_string(),
+        };
+        if let Some(environment) = environment {
+            ensure_environment_exists(conn, environment)?;
+        }
+
+        for name in names {
+            let mut stmt = conn
+                .prepare(
+                    "SELECT s.name, COALESCE(v.value, s.value) FROM secrets s
+                     LEFT JOIN secret_environment_values v
+                       ON v.secret_id = s.id AND v.environment = ?4
+                     WHERE s.name = ?1 AND s.project_id IN (?2, ?3) AND s.deleted_at IS NULL
+                     ORDER BY s.project_id = ?3 LIMIT 1",
+                )
+                .map_err(|e| e.to_string())?;
+
+            if let Ok((name, encrypted)) = stmt.query_row(
+                params![name, project_id, GLOBAL_PROJECT_ID, environment],
+                |row| Ok((row.get::<_, String>(0)?, row.get::<_, Vec<u8>>(1)?)),
+            ) {
+                results.push((name, decrypt_value(&encrypted)?));
+            }
+        }
+
+        Ok(results)
+    })
+}
+
+/// Write secrets to a .env file (see `WriteEnvOptions`)
+pub fn write_env_file(
+    actor: &Actor,
+    keys: &[String],
+    path: &str,
+    options: &WriteEnvOptions,
+) -> Result<WriteEnvReport, String> {
+    let result = render_env_file(keys, path, options);
+
+    let mut requested = keys.to_vec();
+    requested.extend(options.aliases.values().cloned());
+    let operation = if options.dry_run {
+        "diff_env"
+    } else {
+        "write_env"
+    };
+    audit_env_write(actor, operation, requested, path, &result);
+    result
+}
+
+fn render_env_file(
+    keys: &[String],
+    path: &str,
+    options: &WriteEnvOptions,
+) -> Result<WriteEnvReport, String> {
+    // Resolve the path and check it against the allow-list
+    let path = check_write_path(std::path::Path::new(path))?;
+    let path = path.as_path();
+
+    let ResolvedEnv {
+        values,
+        missing,
+        mappings,
+    } = resolve_env_values(keys, options)?;
+
+    // Render the file, starting from the existing one when merging
+    let format = options.format.unwrap_or_else(|| OutputFormat::infer(path));
+    let content = match options.mode {
+        WriteMode::Overwrite => format
+            .formatter(options.kubernetes.as_ref())?
+            .render(&values)?,
+        WriteMode::Merge => {
+            if format != OutputFormat::Dotenv {
+                return Err("Merge mode is only supported for dotenv files".to_string());
+            }
+            let mut document = if path.exists() {
+                dotenv::parse(&std::fs::read_to_string(path).map_err(|e| e.to_string())?)
+            } else {
+                dotenv::Document::default()
+            };
+            for (name, value) in &values {
+                document.set(name, value);
+            }
+            document.to_string()
+        }
+    };
+
+    let report = WriteEnvReport {
+        written: values.len(),
+        missing,
+        diff: None,
+        git: GitCheck::default(),
+        mappings,
+    };
+    save_env_file(path, format, &content, &values, options, report)
+}
+
+/// Fill an env file from a template such as `.env.example`. Each variable
+/// gets the value of the first secret found among its alias, its name
+/// without `prefix`, and its name; the others keep the template's default.
+/// Comments and order are kept. The report's `missing` lists the variables
+/// no secret matched.
+pub fn fill_env_from_template(
+    actor: &Actor,
+    template: &str,
+    path: &str,
+    options: &WriteEnvOptions,
+) -> Result<WriteEnvReport, String> {
+    // Secret names looked up for the template's variables, for the audit log
+    let mut requested = Vec::new();
+    let result = fill_template(template, path, options, &mut requested);
+
+    let operation = if options.dry_run {
+        "diff_env"
+    } else {
+        "fill_env_from_template"
+    };
+    audit_env_write(actor, operation, requested, path, &result);
+    result
+}
+
+fn fill_template(
+    template: &str,
+    path: &str,
+    options: &WriteEnvOptions,
+    requested: &mut Vec<String>,
+) -> Result<WriteEnvReport, String> {
+    let template = check_template_path(std::path::Path::new(template))?;
+    let format = options.format.unwrap_or(OutputFormat::Dotenv);
+    if format != OutputFormat::Dotenv {
+        return Err("Templates can only fill dotenv files".to_string());
+    }
+
+    let content =
+        std::fs::read_to_string(&template).map_err(|e| format!("Cannot read template: {}", e))?;
+    let template = dotenv::parse(&content);
+
+    // Variables with their defaults, in template order
+    let mut variables: Vec<(String, String)> = Vec::new();
+    for (variable, default) in template.entries() {
+        if !variables.iter().any(|(v, _)| v == variable) {
+            variables.push((variable.to_string(), default.to_string()));
+        }
+    }
+
+    // Secret names to try for each variable, most specific first
+    let prefix = options.prefix.as_deref().unwrap_or_default();
+    let candidates: Vec<Vec<String>> = variables
+        .iter()
+        .map(|(variable, _)| {
+            let mut names = Vec::new();
+            if let Some(secret) = options.aliases.get(variable) {
+                names.push(secret.clone());
+            }
+            match variable.strip_prefix(prefix) {
+                Some(name) if !prefix.is_empty() && !name.is_empty() => {
+                    names.push(name.to_string())
+                }
+                _ => {}
+            }
+            names.push(variable.clone());
+            names
+        })
+        .collect();
+
+    let mut names: Vec<String> = Vec::new();
+    for name in candidates.iter().flatten() {
+        if !names.contains(name) {
+            names.push(name.clone());
+        }
+    }
+    requested.clone_from(&names);
+
+    // Resolve the path and check it against the allow-list
+    let path = check_write_path(std::path::Path::new(path))?;
+    let path = path.as_path();
+    let found: HashMap<String, String> = get_values_by_names(
+        &names,
+        options.project.as_deref(),
+        options.environment.as_deref(),
+    )?
+    .into_iter()
+    .collect();
+
+    // Start from the template, or from the existing file when merging
+    let mut document = match options.mode {
+        WriteMode::Overwrite => template,
+        WriteMode::Merge if path.exists() => {
+            dotenv::parse(&std::fs::read_to_string(path).map_err(|e| e.to_string())?)
+        }
+        WriteMode::Merge => dotenv::Document::default(),
+    };
+
+    let mut values = Vec::new();
+    let mut missing = Vec::new();
+    let mut mappings = Vec::new();
+    for ((variable, default), names) in variables.iter().zip(&candidates) {
+        match names.iter().find(|name| found.contains_key(*name)) {
+            Some(secret) => {
+                document.set(variable, &found[secret]);
+                values.push((variable.clone(), found[secret].clone()));
+                mappings.push(KeyMapping {
+                    variable: variable.clone(),
+                    secret: secret.clone(),
+                    found: true,
+                });
+            }
+            None => {
+                // Merging keeps the file's own value over the template default
+                if !document.entries().any(|(key, _)| key == variable) {
+                    document.set(variable, default);
+                }
+                missing.push(variable.clone());
+                mappings.push(KeyMapping {
+                    variable: variable.clone(),
+                    secret: names[0].clone(),
+                    found: false,
+                });
+            }
+        }
+    }
+
+    // Compare every variable of the result, defaults included, for dry runs
+    let mut entries: Vec<(String, String)> = Vec::new();
+    for (key, value) in document.entries() {
+        if !entries.iter().any(|(k, _)| k == key) {
+            entries.push((key.to_string(), value.to_string()));
+        }
+    }
+
+    let report = WriteEnvReport {
+        written: values.len(),
+        missing,
+        diff: None,
+        git: GitCheck::default(),
+        mappings,
+    };
+    save_env_file(
+        path,
+        format,
+        &document.to_string(),
+        &entries,
+        options,
+        report,
+    )
+}
+
+/// Check the target against git, then write `content` keeping a backup of
+/// the old file, or for a dry run only compare `values` with the file
+fn save_env_file(
+    path: &std::path::Path,
+    format: OutputFormat,
+    content: &str,
+    values: &[(String, String)],
+    options: &WriteEnvOptions,
+    mut report: WriteEnvReport,
+) -> Result<WriteEnvReport, String> {
+    // Make sure the file will not be committed by accident
+    report.git = git_check::check(path, options.git, options.dry_run)?;
+
+    if options.dry_run {
+        report.diff = Some(diff_env_file(
+            path,
+            format,
+            options.mode,
+            values,
+            &report.missing,
+        )?);
+        return Ok(report);
+    }
+
+    // Keep the previous contents, then write the file
+    backup_env_file(path, content)?;
+    atomic_file::write(path, content.as_bytes())?;
+
+    Ok(report)
+}
+
+/// Values to write, as worked out by `resolve_env_values`
+struct ResolvedEnv {
+    /// `(variable, value)` pairs in file order
+    values: Vec<(String, String)>,
+    /// Requested secrets that were not found
+    missing: Vec<String>,
+    mappings: Vec<KeyMapping>,
+}
+
+/// Work out which variable each secret is written as, then look up the values
+fn resolve_env_values(keys: &[String], options: &WriteEnvOptions) -> Result<ResolvedEnv, String> {
+    let project = options.project.as_deref();
+    let prefix = options.prefix.as_deref().unwrap_or_default();
+    if !prefix.is_empty() {
+        ensure_variable_name(prefix)?;
+    }
+
+    // Secrets selected by tag come after the requested keys
+    let mut requested: Vec<(String, String)> = keys
+        .iter()
+        .map(|key| (format!("{}{}", prefix, key), key.clone()))
+        .collect();
+    if let Some(tag) = &options.tag {
+        let scope = project.unwrap_or(GLOBAL_PROJECT_ID);
+        let tag = normalize_tag(tag)?;
+        for result in search_secrets(&format!("tag:{}", tag), Some(scope))? {
+            if !keys.contains(&result.name) {
+                requested.push((format!("{}{}", prefix, result.name), result.name));
+            }
+        }
+    }
+    for (variable, secret) in &options.aliases {
+        ensure_variable_name(variable)?;
+        requested.push((variable.clone(), secret.clone()));
+    }
+
+    let mut mappings: Vec<KeyMapping> = Vec::new();
+    for (variable, secret) in requested {
+        match mappings.iter().find(|m| m.variable == variable) {
+            Some(existing) if existing.secret == secret => {}
+            Some(existing) => {
+                return Err(format!(
+                    "{} is mapped to both {} and {}",
+                    variable, existing.secret, secret
+                ))
+            }
+            None => mappings.push(KeyMapping {
+                variable,
+                secret,
+                found: false,
+            }),
+        }
+    }
+
+    // Look up each secret once, even if it is written under several names
+    let mut names: Vec<String> = Vec::new();
+    for mapping in &mappings {
+        if !names.contains(&mapping.secret) {
+            names.push(mapping.secret.clone());
+        }
+    }
+    let found: HashMap<String, String> =
+        get_values_by_names(&names, project, options.environment.as_deref())?
+            .into_iter()
+            .collect();
+
+    let mut values = Vec::new();
+    let mut missing: Vec<String> = Vec::new();
+    for mapping in &mut mappings {
+        match found.get(&mapping.secret) {
+            Some(value) => {
+                mapping.found = true;
+                values.push((mapping.variable.clone(), value.clone()));
+            }
+            None if !missing.contains(&mapping.secret) => missing.push(mapping.secret.clone()),
+            None => {}
+        }
+    }
+
+    Ok(ResolvedEnv {
+        values,
+        missing,
+        mappings,
+    })
+}
+
+/// Variable names must read back from a dotenv file: `[A-Za-z0-9_.-]`, not
+/// starting with a digit
+fn ensure_variable_name(name: &str) -> Result<(), String> {
+    let valid = !name.starts_with(|c: char| c.is_ascii_digit())
+        && !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'));
+    if !valid {
+        return Err(format!("{} is not a valid variable name", name));
+    }
+    Ok(())
+}
+
+/// Compare the values a write would produce with the file on disk
+fn diff_env_file(
+    path: &std::path::Path,
+    format: OutputFormat,
+    mode: WriteMode,
+    values: &[(String, String)],
+    missing: &[String],
+) -> Result<EnvDiff, String> {
+    let mut diff = EnvDiff {
+        missing: missing.to_vec(),
+        ..Default::default()
+    };
+
+    // Hash of the first assignment of each key, as `Document::set` updates that one
+    let mut existing: Vec<(String, [u8; 32])> = Vec::new();
+    if std::fs::symlink_metadata(path).is_ok_and(|metadata| metadata.is_file()) {
+        // Other formats are only written, so every key may change
+        if format != OutputFormat::Dotenv {
+            diff.not_comparable = values.iter().map(|(name, _)| name.clone()).collect();
+            return Ok(diff);
+        }
+        let document = dotenv::parse(&std::fs::read_to_string(path).map_err(|e| e.to_string())?);
+        for (key, value) in document.entries() {
+            if !existing.iter().any(|(k, _)| k == key) {
+                existing.push((key.to_string(), Sha256::digest(value).into()));
+            }
+        }
+    }
+
+    for (name, value) in values {
+        let hash: [u8; 32] = Sha256::digest(value).into();
+        match existing.iter().find(|(k, _)| k == name) {
+            None => diff.added.push(name.clone()),
+            Some((_, current)) if *current == hash => diff.unchanged.push(name.clone()),
+            Some(_) => diff.changed.push(name.clone()),
+        }
+    }
+    if mode == WriteMode::Overwrite {
+        diff.removed = existing
+            .into_iter()
+            .map(|(key, _)| key)
+            .filter(|key| !values.iter().any(|(name, _)| name == key))
+            .collect();
+    }
+
+    Ok(diff)
+}
+
+/// Back up an existing env file before it is replaced with `new_content`.
+/// Nothing is kept if the file does not exist or would not change.
+fn backup_env_file(path: &std::path::Path, new_content: &str) -> Result<(), String> {
+    // Symlinks are refused by the write itself; never read through them
+    match std::fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.is_file() => {}
+        _ => return Ok(()),
+    }
+    let bytes = std::fs::read(path).map_err(|e| e.to_string())?;
+    if bytes == new_content.as_bytes() {
+        return Ok(());
+    }
+    let previous = String::from_utf8(bytes)
+        .map_err(|_| format!("Cannot back up {}: not a text file", path.display()))?;
+
+    let now = Utc::now();
+    let file_name = format!(
+        "{}-{}.bak",
+        now.format("%Y%m%dT%H%M%S%.3fZ"),
+        Uuid::new_v4().simple()
+    );
+    let encrypted = encrypt_value(&previous)?;
+    atomic_file::write(&get_env_backup_dir().join(&file_name), &encrypted)?;
+
+    let path = path.to_string_lossy();
+    with_db(|conn| {
+        conn.execute(
+            "INSERT INTO env_backups (path, file_name, size, created_at) VALUES (?, ?, ?, ?)",
+            params![path, file_name, previous.len() as i64, now.timestamp()],
+        )
+        .map_err(|e| e.to_string())?;
+        prune_env_backups(conn, &path)
+    })
+}
+
+/// Delete the oldest backups of a file beyond the retention limit
+fn prune_env_backups(conn: &Connection, path: &str) -> Result<(), String> {
+    let retention = match get_setting(conn, ENV_BACKUP_RETENTION)? {
+        Some(value) => value
+            .parse::<u32>()
+            .map_err(|_| format!("Invalid {} setting", ENV_BACKUP_RETENTION))?,
+        None => DEFAULT_ENV_BACKUP_RETENTION,
+    };
+
+    let mut stmt = conn
+        .prepare("SELECT id, file_name FROM env_backups WHERE path = ? ORDER BY id DESC LIMIT -1 OFFSET ?")
+        .map_err(|e| e.to_string())?;
+    let expired = stmt
+        .query_map(params![path, retention], |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+        })
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    let dir = get_env_backup_dir();
+    for (id, file_name) in expired {
+        conn.execute("DELETE FROM env_backups WHERE id = ?", params![id])
+            .map_err(|e| e.to_string())?;
+        std::fs::remove_file(dir.join(file_name)).ok();
+    }
+    Ok(())
+}
+
+/// List env file backups, newest first, optionally for one file only
+pub fn list_env_backups(path: Option<&str>) -> Result<Vec<EnvBackup>, String> {
+    with_db(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, path, size, created_at FROM env_backups
+                 WHERE ?1 IS NULL OR path = ?1 ORDER BY id DESC",
+            )
+            .map_err(|e| e.to_string())?;
+
+        let backups = stmt
+            .query_map(params![path], |row| {
+                Ok(EnvBackup {
+                    id: row.get(0)?,
+                    path: row.get(1)?,
+                    size: row.get(2)?,
+                    created_at: row.get(3)?,
+                })
+            })
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+
+        Ok(backups)
+    })
+}
+
+/// Write a backup back to the file it was taken from, after the path policy
+/// and git check `write_env_file` runs; the current contents are backed up
+/// first
+pub fn restore_env_backup(actor: &Actor, id: i64, git: GitPolicy) -> Result<EnvBackup, String> {
+    let result = restore_backup(id, git);
+
+    let path: Option<String> = match &result {
+        Ok(backup) => Some(backup.path.clone()),
+        Err(_) => with_db(|conn| {
+            conn.query_row(
+                "SELECT path FROM env_backups WHERE id = ?",
+                params![id],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| e.to_string())
+        })?,
+    };
+    audit(actor, "restore_env_backup", &[], path.as_deref(), &result);
+    result
+}
+
+fn restore_backup(id: i64, git: GitPolicy) -> Result<EnvBackup, String> {
+    vault::ensure_unlocked()?;
+
+    let (backup, file_name) = with_db(|conn| {
+        conn.query_row(
+            "SELECT id, path, size, created_at, file_name FROM env_backups WHERE id = ?",
+            params![id],
+            |row| {
+                Ok((
+                    EnvBackup {
+                        id: row.get(0)?,
+                        path: row.get(1)?,
+                        size: row.get(2)?,
+                        created_at: row.get(3)?,
+                    },
+                    row.get::<_, String>(4)?,
+                ))
+            },
+        )
+        .optional()
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Backup not found".to_string())
+    })?;
+
+    let encrypted = std::fs::read(get_env_backup_dir().join(file_name))
+        .map_err(|e| format!("Cannot read backup: {}", e))?;
+    let content = decrypt_value(&encrypted)?;
+
+    // The same checks as write_env: the policy may have changed since
+    let path = check_write_path(std::path::Path::new(&backup.path))?;
+    git_check::check(&path, git, false)?;
+
+    backup_env_file(&path, &content)?;
+    atomic_file::write(&path, content.as_bytes())?;
+    Ok(backup)
+}
+
+/// Get how many backups are kept per env file
+pub fn get_env_backup_retention() -> Result<u32, String> {
+    with_db(|conn| match get_setting(conn, ENV_BACKUP_RETENTION)? {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("Invalid {} setting", ENV_BACKUP_RETENTION)),
+        None => Ok(DEFAULT_ENV_BACKUP_RETENTION),
+    })
+}
+
+/// Set how many backups are kept per env file
+pub fn set_env_backup_retention(backups: u32) -> Result<(), String> {
+    with_db(|conn| {
+        set_setting(conn, ENV_BACKUP_RETENTION, &backups.to_string())?;
+
+        let paths: Vec<String> = conn
+            .prepare("SELECT DISTINCT path FROM env_backups")
+            .map_err(|e| e.to_string())?
+            .query_map([], |row| row.get(0))
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        for path in paths {
+            prune_env_backups(conn, &path)?;
+        }
+        Ok(())
+    })
+}
+
+/// Get where env files may be written
+pub fn get_path_policy() -> Result<PathPolicy, String> {
+    with_db(|conn| match get_setting(conn, WRITE_PATH_POLICY)? {
+        Some(value) => serde_json::from_str(&value)
+            .map_err(|_| format!("Invalid {} setting", WRITE_PATH_POLICY)),
+        None => Ok(PathPolicy::default()),
+    })
+}
+
+/// Set where env files may be written
+pub fn set_path_policy(policy: &PathPolicy) -> Result<(), String> {
+    policy.validate()?;
+    let value = serde_json::to_string(policy).map_err(|e| e.to_string())?;
+    with_db(|conn| set_setting(conn, WRITE_PATH_POLICY, &value))
+}
+
+/// Resolve a path to write to and check it against the path policy. The
+/// app's data directory is refused whatever the policy allows, so a write
+/// cannot replace the database, backups or the MCP endpoint's token.
+pub fn check_write_path(path: &std::path::Path) -> Result<PathBuf, String> {
+    get_path_policy()?.check_outside(path, &get_app_dir())
+}
+
+/// Resolve a template to read and check it against the same policy as
+/// writes, so a template cannot be, say, an SSH key or the app's database
+pub fn check_template_path(path: &std::path::Path) -> Result<PathBuf, String> {
+    if !path.is_absolute() {
+        return Err("Template path must be absolute".to_string());
+    }
+    let path = get_path_policy()?.check_outside(path, &get_app_dir())?;
+    if !path.is_file() {
+        return Err(format!("Template not found: {}", path.display()));
+    }
+    Ok(path)
+}
+
+/// Queue a write for the user's approval. Returns false, queuing nothing, if
+/// a rule already allows writes for its project or file.
+pub fn queue_write_approval(approval: &WriteApproval) -> Result<bool, String> {
+    let keys = serde_json::to_string(&approval.keys).map_err(|e| e.to_string())?;
+    with_db(|conn| {
+        let project_id = match &approval.project {
+            Some(project) => resolve_project_id(conn, project)?,
+            None => GLOBAL_PROJECT_ID.to_string(),
+        };
+        let allowed = conn
+            .query_row(
+                "SELECT 1 FROM approval_rules WHERE project_id = ? OR path = ?",
+                params![project_id, approval.path],
+                |_| Ok(()),
+            )
+            .optional()
+            .map_err(|e| e.to_string())?
+            .is_some();
+        if allowed {
+            return Ok(false);
+        }
+
+        conn.execute(
+            "INSERT INTO write_approvals
+                (id, client, tool, keys, tag, path, project, environment, template, mode, format, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            params![
+                approval.id,
+                approval.client,
+                approval.tool,
+                keys,
+                approval.tag,
+                approval.path,
+                approval.project,
+                approval.environment,
+                approval.template,
+                approval.mode.as_str(),
+                approval.format.as_str(),
+                approval.created_at
+            ],
+        )
+        .map_err(|e| e.to_string())?;
+        Ok(true)
+    })
+}
+
+/// Get the state of a queued write
+pub fn get_write_approval_status(id: &str) -> Result<ApprovalStatus, String> {
+    with_db(|conn| read_approval_status(conn, id))
+}
+
+fn read_approval_status(conn: &Connection, id: &str) -> Result<ApprovalStatus, String> {
+    let status: String = conn
+        .query_row(
+            "SELECT status FROM write_approvals WHERE id = ?",
+            params![id],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Write request not found: {}", id))?;
+    ApprovalStatus::parse(&status)
+}
+
+/// Approve or deny a pending write. Approving with `remember` also allows
+/// later writes for the same project or file without asking.
+pub fn decide_write_approval(
+    id: &str,
+    approved: bool,
+    remember: Option<ApprovalScope>,
+) -> Result<(), String> {
+    let status = if approved {
+        ApprovalStatus::Approved
+    } else {
+        ApprovalStatus::Denied
+    };
+    let now = Utc::now().timestamp();
+
+    with_db(|conn| {
+        let changed = conn
+            .execute(
+                "UPDATE write_approvals SET status = ?, decided_at = ? WHERE id = ? AND status = 'pending'",
+                params![status.as_str(), now, id],
+            )
+            .map_err(|e| e.to_string())?;
+        if changed == 0 {
+            return Err(format!(
+                "Write request is no longer pending ({})",
+                read_approval_status(conn, id)?.as_str()
+            ));
+        }
+
+        let Some(scope) = remember.filter(|_| approved) else {
+            return Ok(());
+        };
+        let (project, path): (Option<String>, String) = conn
+            .query_row(
+                "SELECT project, path FROM write_approvals WHERE id = ?",
+                params![id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .map_err(|e| e.to_string())?;
+        let (project_id, path) = match scope {
+            ApprovalScope::Project => {
+                let project_id = match project {
+                    Some(project) => resolve_project_id(conn, &project)?,
+                    None => GLOBAL_PROJECT_ID.to_string(),
+                };
+                (Some(project_id), None)
+            }
+            ApprovalScope::Path => (None, Some(path)),
+        };
+        conn.execute(
+            "INSERT INTO approval_rules (project_id, path, created_at) VALUES (?, ?, ?)",
+            params![project_id, path, now],
+        )
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    })
+}
+
+/// Give up on a write nobody decided on. Returns its final state, which may
+/// have been decided meanwhile.
+pub fn expire_write_approval(id: &str) -> Result<ApprovalStatus, String> {
+    let now = Utc::now().timestamp();
+    with_db(|conn| {
+        conn.execute(
+            "UPDATE write_approvals SET status = 'expired', decided_at = ? WHERE id = ? AND status = 'pending'",
+            params![now, id],
+        )
+        .map_err(|e| e.to_string())?;
+        read_approval_status(conn, id)
+    })
+}
+
+/// Expire writes pending for longer than `timeout_secs`, whose requester
+/// may be gone, and delete decided ones after a day
+pub fn expire_write_approvals(timeout_secs: i64) -> Result<(), String> {
+    let now = Utc::now().timestamp();
+    with_db(|conn| {
+        conn.execute(
+            "UPDATE write_approvals SET status = 'expired', decided_at = ?1
+             WHERE status = 'pending' AND created_at < ?2",
+            params![now, now - timeout_secs],
+        )
+        .map_err(|e| e.to_string())?;
+        conn.execute(
+            "DELETE FROM write_approvals WHERE status != 'pending' AND decided_at < ?",
+            params![now - APPROVAL_HISTORY_SECS],
+        )
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    })
+}
+
+/// List writes waiting for the user, oldest first
+pub fn list_write_approvals() -> Result<Vec<WriteApproval>, String> {
+    with_db(|conn| read_pending_approvals(conn, false))
+}
+
+/// Pending writes the desktop app has not announced yet, marking them
+/// announced
+pub fn take_new_write_approvals() -> Result<Vec<WriteApproval>, String> {
+    with_db(|conn| {
+        let approvals = read_pending_approvals(conn, true)?;
+        for approval in &approvals {
+            conn.execute(
+                "UPDATE write_approvals SET notified = 1 WHERE id = ?",
+                params![approval.id],
+            )
+            .map_err(|e| e.to_string())?;
+        }
+        Ok(approvals)
+    })
+}
+
+fn read_pending_approvals(conn: &Connection, only_new: bool) -> Result<Vec<WriteApproval>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, client, tool, keys, tag, path, project, environment, template, mode, format, created_at
+             FROM write_approvals
+             WHERE status = 'pending' AND (?1 = 0 OR notified = 0) ORDER BY created_at, id",
+        )
+        .map_err(|e| e.to_string())?;
+
+    let rows = stmt
+        .query_map(params![only_new], |row| {
+            Ok((
+                WriteApproval {
+                    id: row.get(0)?,
+                    client: row.get(1)?,
+                    tool: row.get(2)?,
+                    keys: Vec::new(),
+                    tag: row.get(4)?,
+                    path: row.get(5)?,
+                    project: row.get(6)?,
+                    environment: row.get(7)?,
+                    template: row.get(8)?,
+                    mode: WriteMode::default(),
+                    format: OutputFormat::Dotenv,
+                    created_at: row.get(11)?,
+                },
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(9)?,
+                row.get::<_, String>(10)?,
+            ))
+        })
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    rows.into_iter()
+        .map(|(approval, keys, mode, format)| {
+            Ok(WriteApproval {
+                keys: serde_json::from_str(&keys).map_err(|e| e.to_string())?,
+                mode: WriteMode::parse(&mode)?,
+                format: OutputFormat::parse(&format)?,
+                ..approval
+            })
+        })
+        .collect()
+}
+
+/// List the rules that approve MCP writes without asking
+pub fn list_approval_rules() -> Result<Vec<ApprovalRule>, String> {
+    with_db(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT r.id, r.project_id, p.name, r.path, r.created_at FROM approval_rules r
+                 LEFT JOIN projects p ON p.id = r.project_id ORDER BY r.id",
+            )
+            .map_err(|e| e.to_string())?;
+
+        let rules = stmt
+            .query_map([], |row| {
+                Ok(ApprovalRule {
+                    id: row.get(0)?,
+                    project_id: row.get(1)?,
+                    project: row.get(2)?,
+                    path: row.get(3)?,
+                    created_at: row.get(4)?,
+                })
+            })
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+
+        Ok(rules)
+    })
+}
+
+/// Delete an approval rule; later writes it covered need approval again
+pub fn delete_approval_rule(id: i64) -> Result<bool, String> {
+    with_db(|conn| {
+        let deleted = conn
+            .execute("DELETE FROM approval_rules WHERE id = ?", params![id])
+            .map_err(|e| e.to_string())?;
+        Ok(deleted > 0)
+    })
+}
+
+/// Record an operation and its outcome in the audit log. A failure to record
+/// is reported but does not fail the operation, which has already happened.
+pub fn audit<T>(
+    actor: &Actor,
+    operation: &str,
+    secrets: &[String],
+    path: Option<&str>,
+    result: &Result<T, String>,
+) {
+    let recorded = serde_json::to_string(secrets)
+        .map_err(|e| e.to_string())
+        .and_then(|secrets| {
+            let record = AuditRecord {
+                created_at: Utc::now().timestamp(),
+                actor: actor.to_string(),
+                operation: operation.to_string(),
+                secrets,
+                path: path.map(str::to_string),
+                success: result.is_ok(),
+                error: result.as_ref().err().cloned(),
+            };
+            with_db(|conn| append_audit_record(conn, &record))
+        });
+    if let Err(e) = recorded {
+        eprintln!("Failed to write audit log: {}", e);
+    }
+}
+
+/// Append `record` to the audit log, chained to the newest entry
+fn append_audit_record(conn: &Connection, record: &AuditRecord) -> Result<(), String> {
+    // Immediate, so the MCP server cannot append between reading the newest
+    // hash and inserting
+    let tx = Transaction::new_unchecked(conn, TransactionBehavior::Immediate)
+        .map_err(|e| e.to_string())?;
+    let prev_hash: Option<String> = tx
+        .query_row(
+            "SELECT hash FROM audit_log ORDER BY id DESC LIMIT 1",
+            [],
+            |row| row.get(0),
+        )
+        .optional()
+        .map_err(|e| e.to_string())?
+        .flatten();
+
+    tx.execute(
+        "INSERT INTO audit_log (created_at, actor, operation, secrets, path, success, error, hash)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        params![
+            record.created_at,
+            record.actor,
+            record.operation,
+            record.secrets,
+            record.path,
+            record.success,
+            record.error,
+            record.hash(prev_hash.as_deref())
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+    tx.commit().map_err(|e| e.to_string())
+}
+
+/// Audit an env file write: the secrets written if it succeeded, the
+/// requested ones otherwise
+fn audit_env_write(
+    actor: &Actor,
+    operation: &str,
+    requested: Vec<String>,
+    path: &str,
+    result: &Result<WriteEnvReport, String>,
+) {
+    let secrets = match result {
+        Ok(report) => {
+            let mut written: Vec<String> = Vec::new();
+            for mapping in report.mappings.iter().filter(|m| m.found) {
+                if !written.contains(&mapping.secret) {
+                    written.push(mapping.secret.clone());
+                }
+            }
+            written
+        }
+        Err(_) => requested,
+    };
+    audit(actor, operation, &secrets, Some(path), result);
+}
+
+/// Query the audit log, newest first
+pub fn query_audit_log(filter: &AuditFilter) -> Result<Vec<AuditEntry>, String> {
+    let actor_prefix = filter
+        .actor
+        .as_deref()
+        .filter(|actor| *actor == "mcp")
+        .map(|_| "mcp:");
+    let actor = filter.actor.as_deref().filter(|_| actor_prefix.is_none());
+    let path = filter
+        .path
+        .as_deref()
+        .map(|path| path.trim_end_matches('/'));
+
+    with_db(|conn| {
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, created_at, actor, operation, secrets, path, success, error FROM audit_log a
+                 WHERE (?1 IS NULL OR actor = ?1)
+                   AND (?2 IS NULL OR substr(actor, 1, length(?2)) = ?2)
+                   AND (?3 IS NULL OR operation = ?3)
+                   AND (?4 IS NULL OR EXISTS (SELECT 1 FROM json_each(a.secrets) WHERE value = ?4))
+                   AND (?5 IS NULL OR path = ?5 OR substr(path, 1, length(?5) + 1) = ?5 || '/')
+                   AND (?6 IS NULL OR created_at >= ?6)
+                   AND (?7 IS NULL OR created_at <= ?7)
+                   AND (?8 IS NULL OR success = ?8)
+                 ORDER BY id DESC LIMIT ?9",
+            )
+            .map_err(|e| e.to_string())?;
+
+        let rows = stmt
+            .query_map(
+                params![
+                    actor,
+                    actor_prefix,
+                    filter.operation,
+                    filter.secret,
+                    path,
+                    filter.since,
+                    filter.until,
+                    filter.success,
+                    filter.limit.map_or(-1, i64::from)
+                ],
+                |row| {
+                    Ok((
+                        AuditEntry {
+                            id: row.get(0)?,
+                            created_at: row.get(1)?,
+                            actor: row.get(2)?,
+                            operation: row.get(3)?,
+                            secrets: Vec::new(),
+                            path: row.get(5)?,
+                            success: row.get(6)?,
+                            error: row.get(7)?,
+                        },
+                        row.get::<_, String>(4)?,
+                    ))
+                },
+            )
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+
+        rows.into_iter()
+            .map(|(entry, secrets)| {
+                Ok(AuditEntry {
+                    secrets: serde_json::from_str(&secrets).map_err(|e| e.to_string())?,
+                    ..entry
+                })
+            })
+            .collect()
+    })
+}
+
+/// Write the matching audit log entries to `path` as JSON Lines, oldest
+/// first. Returns the number of entries written.
+pub fn export_audit_log(filter: &AuditFilter, path: &str) -> Result<usize, String> {
+    let path = std::path::Path::new(path);
+    if !path.is_absolute() {
+        return Err("Path must be absolute".to_string());
+    }
+
+    let entries = query_audit_log(filter)?;
+    let mut content = String::new();
+    for entry in entries.iter().rev() {
+        content += &serde_json::to_string(entry).map_err(|e| e.to_string())?;
+        content.push('\n');
+    }
+    atomic_file::write(path, content.as_bytes())?;
+    Ok(entries.len())
+}
+
+/// Walk the audit log's hash chain, oldest first, and report the first entry
+/// that does not match
+pub fn verify_audit_log() -> Result<AuditVerification, String> {
+    with_db(verify_audit_chain)
+}
+
+fn verify_audit_chain(conn: &Connection) -> Result<AuditVerification, String> {
+    // Without the setting every entry has to be chained
+    let chain_start: i64 = match get_setting(conn, AUDIT_CHAIN_START)? {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("Invalid {} setting", AUDIT_CHAIN_START))?,
+        None => i64::MIN,
+    };
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, created_at, actor, operation, secrets, path, success, error, hash
+             FROM audit_log ORDER BY id",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                AuditRecord {
+                    created_at: row.get(1)?,
+                    actor: row.get(2)?,
+                    operation: row.get(3)?,
+                    secrets: row.get(4)?,
+                    path: row.get(5)?,
+                    success: row.get(6)?,
+                    error: row.get(7)?,
+                },
+                row.get::<_, Option<String>>(8)?,
+            ))
+        })
+        .map_err(|e| e.to_string())?;
+
+    let mut verification = AuditVerification {
+        valid: true,
+        entries: 0,
+        unchained: 0,
+        first_broken: None,
+        last_hash: None,
+    };
+    for row in rows {
+        let (id, record, hash) = row.map_err(|e| e.to_string())?;
+        verification.entries += 1;
+
+        // Entries from before the chain are unhashed; any other missing hash
+        // is a break
+        if id < chain_start && hash.is_none() {
+            verification.unchained += 1;
+            continue;
+        }
+        let expected = record.hash(verification.last_hash.as_deref());
+        if id < chain_start || hash.as_deref() != Some(expected.as_str()) {
+            verification.valid = false;
+            verification.first_broken = Some(id);
+            verification.last_hash = None;
+            break;
+        }
+        verification.last_hash = Some(expected);
+    }
+    Ok(verification)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TestApp;
+
+    fn audit_database(entries: usize) -> Connection {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrations::migrate(&mut conn).unwrap();
+        for i in 0..entries {
+            append_audit_record(&conn, &record(i as i64)).unwrap();
+        }
+        conn
+    }
+
+    fn record(i: i64) -> AuditRecord {
+        AuditRecord {
+            created_at: 1_700_000_000 + i,
+            actor: "desktop".to_string(),
+            operation: "get_secret".to_string(),
+            secrets: format!("[\"SECRET_{}\"]", i),
+            path: None,
+            success: true,
+            error: None,
+        }
+    }
+
+    /// Tampering needs the append-only triggers out of the way
+    fn drop_triggers(conn: &Connection) {
+        conn.execute_batch(
+            "DROP TRIGGER audit_log_no_update;
+             DROP TRIGGER audit_log_no_delete;
+             DROP TRIGGER audit_chain_start_no_update;
+             DROP TRIGGER audit_chain_start_no_delete;",
+        )
+        .unwrap();
+    }
+
+    fn first_broken(conn: &Connection) -> Option<i64> {
+        let verification = verify_audit_chain(conn).unwrap();
+        assert_eq!(verification.valid, verification.first_broken.is_none());
+        verification.first_broken
+    }
+
+    #[test]
+    fn hash_matches_json_stringify() {
+        let record = AuditRecord {
+            created_at: 1_700_000_000,
+            actor: "mcp:cursor".to_string(),
+            operation: "write_env".to_string(),
+            secrets: "[\"OPENAI_API_KEY\"]".to_string(),
+            path: Some("/tmp/é.env".to_string()),
+            success: false,
+            error: Some("The user denied \"x\"\n".to_string()),
+        };
+
+        // JSON.stringify and SHA-256 of the same array in Node
+        assert_eq!(
+            record.hash(None),
+            "e676e5b9954602c6f74e632cd10245239c4203da9098b38b03c46bf93fb56057"
+        );
+        assert_eq!(
+            record.hash(Some("abc")),
+            "f0167e06b115681328e7b56d9dc22bba367bc6e4e306b9dfb756c9bde2568a68"
+        );
+    }
+
+    #[test]
+    fn intact_chain_verifies() {
+        let conn = audit_database(3);
+
+        let verification = verify_audit_chain(&conn).unwrap();
+        assert!(verification.valid);
+        assert_eq!(verification.entries, 3);
+        assert_eq!(verification.unchained, 0);
+        let newest: String = conn
+            .query_row("SELECT hash FROM audit_log WHERE id = 3", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(verification.last_hash, Some(newest));
+    }
+
+    #[test]
+    fn append_only_triggers_block_tampering() {
+        let conn = audit_database(1);
+
+        assert!(conn
+            .execute("UPDATE audit_log SET success = 0", [])
+            .is_err());
+        assert!(conn.execute("DELETE FROM audit_log", []).is_err());
+    }
+
+    #[test]
+    fn detects_modified_entry() {
+        let conn = audit_database(3);
+        drop_triggers(&conn);
+
+        conn.execute(
+            "UPDATE audit_log SET secrets = '[\"OTHER\"]' WHERE id = 2",
+            [],
+        )
+        .unwrap();
+
+        assert_eq!(first_broken(&conn), Some(2));
+    }
+
+    #[test]
+    fn detects_deleted_entry() {
+        let conn = audit_database(3);
+        drop_triggers(&conn);
+
+        conn.execute("DELETE FROM audit_log WHERE id = 2", [])
+            .unwrap();
+
+        assert_eq!(first_broken(&conn), Some(3));
+    }
+
+    #[test]
+    fn detects_reordered_entries() {
+        let conn = audit_database(3);
+        drop_triggers(&conn);
+
+        // Swap entries 2 and 3, hashes included
+        conn.execute_batch(
+            "UPDATE audit_log SET id = 0 WHERE id = 2;
+             UPDATE audit_log SET id = 2 WHERE id = 3;
+             UPDATE audit_log SET id = 3 WHERE id = 0;",
+        )
+        .unwrap();
+
+        assert_eq!(first_broken(&conn), Some(2));
+    }
+
+    #[test]
+    fn detects_rehashed_entry_without_its_successor() {
+        let conn = audit_database(3);
+        drop_triggers(&conn);
+
+        // Rewriting an entry along with its own hash still breaks the link
+        // from the next one
+        let mut forged = record(1);
+        forged.success = false;
+        let prev_hash: String = conn
+            .query_row("SELECT hash FROM audit_log WHERE id = 1", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        conn.execute(
+            "UPDATE audit_log SET success = 0, hash = ? WHERE id = 2",
+            [forged.hash(Some(&prev_hash))],
+        )
+        .unwrap();
+
+        assert_eq!(first_broken(&conn), Some(3));
+    }
+
+    #[test]
+    fn entries_before_the_chain_are_unchained() {
+        let conn = audit_database(0);
+        drop_triggers(&conn);
+        conn.execute(
+            "UPDATE settings SET value = '2' WHERE key = ?",
+            [AUDIT_CHAIN_START],
+        )
+        .unwrap();
+        let legacy = record(0);
+        conn.execute(
+            "INSERT INTO audit_log (created_at, actor, operation, secrets, success)
+             VALUES (?, ?, ?, ?, ?)",
+            params![
+                legacy.created_at,
+                legacy.actor,
+                legacy.operation,
+                legacy.secrets,
+                legacy.success
+            ],
+        )
+        .unwrap();
+        append_audit_record(&conn, &record(1)).unwrap();
+        append_audit_record(&conn, &record(2)).unwrap();
+
+        let verification = verify_audit_chain(&conn).unwrap();
+        assert!(verification.valid);
+        assert_eq!(verification.entries, 3);
+        assert_eq!(verification.unchained, 1);
+
+        // Once the chain has started, a missing hash is a break
+        conn.execute("UPDATE audit_log SET hash = NULL WHERE id = 3", [])
+            .unwrap();
+        assert_eq!(first_broken(&conn), Some(3));
+        conn.execute("UPDATE audit_log SET hash = NULL WHERE id = 2", [])
+            .unwrap();
+        assert_eq!(first_broken(&conn), Some(2));
+    }
+
+    #[test]
+    fn detects_removed_hashes() {
+        let conn = audit_database(3);
+        drop_triggers(&conn);
+
+        // Unhashed entries would pass as older than the chain if it could
+        // start anywhere
+        conn.execute("UPDATE audit_log SET hash = NULL WHERE id < 3", [])
+            .unwrap();
+        assert_eq!(first_broken(&conn), Some(1));
+
+        conn.execute("UPDATE audit_log SET hash = NULL", [])
+            .unwrap();
+        assert_eq!(first_broken(&conn), Some(1));
+    }
+
+    #[test]
+    fn chain_start_cannot_be_moved() {
+        let conn = audit_database(3);
+
+        assert!(conn
+            .execute(
+                "UPDATE settings SET value = '4' WHERE key = ?",
+                [AUDIT_CHAIN_START]
+            )
+            .is_err());
+        assert!(conn
+            .execute("DELETE FROM settings WHERE key = ?", [AUDIT_CHAIN_START])
+            .is_err());
+
+        // Without it, every entry has to be chained
+        drop_triggers(&conn);
+        conn.execute("DELETE FROM settings WHERE key = ?", [AUDIT_CHAIN_START])
+            .unwrap();
+        conn.execute("UPDATE audit_log SET hash = NULL WHERE id = 1", [])
+            .unwrap();
+        assert_eq!(first_broken(&conn), Some(1));
+    }
+
+    fn approval(id: &str, path: &str, project: Option<&str>) -> WriteApproval {
+        WriteApproval {
+            id: id.to_string(),
+            client: "cursor".to_string(),
+            tool: "write_env".to_string(),
+            keys: vec!["STRIPE_KEY".to_string()],
+            tag: None,
+            path: path.to_string(),
+            project: project.map(String::from),
+            environment: None,
+            template: None,
+            mode: WriteMode::Overwrite,
+            format: OutputFormat::Dotenv,
+            created_at: Utc::now().timestamp(),
+        }
+    }
+
+    #[test]
+    fn approved_write_is_queued_once() {
+        let _app = TestApp::new();
+
+        assert!(queue_write_approval(&approval("a", "/w/.env", None)).unwrap());
+        assert_eq!(
+            get_write_approval_status("a").unwrap(),
+            ApprovalStatus::Pending
+        );
+        let pending = list_write_approvals().unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].keys, ["STRIPE_KEY"]);
+        assert_eq!(pending[0].environment, None);
+        assert_eq!(pending[0].mode, WriteMode::Overwrite);
+        assert_eq!(take_new_write_approvals().unwrap().len(), 1);
+        assert!(take_new_write_approvals().unwrap().is_empty());
+
+        decide_write_approval("a", true, None).unwrap();
+        assert_eq!(
+            get_write_approval_status("a").unwrap(),
+            ApprovalStatus::Approved
+        );
+        assert!(list_write_approvals().unwrap().is_empty());
+        let err = decide_write_approval("a", false, None).unwrap_err();
+        assert!(err.contains("no longer pending (approved)"), "{}", err);
+        assert!(list_approval_rules().unwrap().is_empty());
+    }
+
+    #[test]
+    fn approval_shows_what_would_be_written() {
+        let _app = TestApp::new();
+        let request = WriteApproval {
+            environment: Some("production".to_string()),
+            template: Some("/w/.env.example".to_string()),
+            mode: WriteMode::Merge,
+            format: OutputFormat::Kubernetes,
+            ..approval("c", "/w/secret.yaml", None)
+        };
+        queue_write_approval(&request).unwrap();
+
+        let pending = take_new_write_approvals().unwrap();
+        assert_eq!(pending[0].environment.as_deref(), Some("production"));
+        assert_eq!(pending[0].template.as_deref(), Some("/w/.env.example"));
+        assert_eq!(pending[0].mode, WriteMode::Merge);
+        assert_eq!(pending[0].format, OutputFormat::Kubernetes);
+    }
+
+    #[test]
+    fn denied_write_remembers_nothing() {
+        let _app = TestApp::new();
+
+        assert!(queue_write_approval(&approval("d", "/w/.env", None)).unwrap());
+        decide_write_approval("d", false, Some(ApprovalScope::Path)).unwrap();
+        assert_eq!(
+            get_write_approval_status("d").unwrap(),
+            ApprovalStatus::Denied
+        );
+        assert!(list_approval_rules().unwrap().is_empty());
+        assert!(queue_write_approval(&approval("d2", "/w/.env", None)).unwrap());
+    }
+
+    #[test]
+    fn undecided_write_expires() {
+        let _app = TestApp::new();
+        let old = WriteApproval {
+            created_at: Utc::now().timestamp() - 300,
+            ..approval("old", "/w/.env", None)
+        };
+        queue_write_approval(&old).unwrap();
+        queue_write_approval(&approval("new", "/w/.env", None)).unwrap();
+
+        expire_write_approvals(120).unwrap();
+        assert_eq!(
+            get_write_approval_status("old").unwrap(),
+            ApprovalStatus::Expired
+        );
+        assert_eq!(
+            get_write_approval_status("new").unwrap(),
+            ApprovalStatus::Pending
+        );
+        assert!(decide_write_approval("old", true, None).is_err());
+
+        // A waiting write gives up, unless it was decided meanwhile
+        assert_eq!(
+            expire_write_approval("new").unwrap(),
+            ApprovalStatus::Expired
+        );
+        assert!(list_write_approvals().unwrap().is_empty());
+        queue_write_approval(&approval("late", "/w/.env", None)).unwrap();
+        decide_write_approval("late", false, None).unwrap();
+        assert_eq!(
+            expire_write_approval("late").unwrap(),
+            ApprovalStatus::Denied
+        );
+    }
+
+    #[test]
+    fn project_rule_skips_the_queue() {
+        let _app = TestApp::new();
+        create_project("web", None).unwrap();
+
+        queue_write_approval(&approval("p", "/w/.env", Some("web"))).unwrap();
+        decide_write_approval("p", true, Some(ApprovalScope::Project)).unwrap();
+        let rules = list_approval_rules().unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].project.as_deref(), Some("web"));
+        assert_eq!(rules[0].path, None);
+
+        assert!(!queue_write_approval(&approval("p2", "/elsewhere/.env", Some("web"))).unwrap());
+        // Other projects still ask
+        assert!(queue_write_approval(&approval("p3", "/w/.env", None)).unwrap());
+
+        assert!(delete_approval_rule(rules[0].id).unwrap());
+        assert!(queue_write_approval(&approval("p4", "/w/.env", Some("web"))).unwrap());
+    }
+
+    #[test]
+    fn path_rule_skips_the_queue_for_that_file_only() {
+        let _app = TestApp::new();
+        create_project("web", None).unwrap();
+
+        queue_write_approval(&approval("f", "/w/.env", None)).unwrap();
+        decide_write_approval("f", true, Some(ApprovalScope::Path)).unwrap();
+        let rules = list_approval_rules().unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].path.as_deref(), Some("/w/.env"));
+        assert_eq!(rules[0].project_id, None);
+
+        assert!(!queue_write_approval(&approval("f2", "/w/.env", Some("web"))).unwrap());
+        assert!(queue_write_approval(&approval("f3", "/w/.env.local", None)).unwrap());
+        assert!(queue_write_approval(&approval("f4", "/other/.env", None)).unwrap());
+    }
+
+    fn add_secret(name: &str, value: &str) {
+        create_secret(&Actor::Desktop, name, None, value, None, &[]).unwrap();
+    }
+
+    fn fill(app: &TestApp, template: &str, options: &WriteEnvOptions) -> WriteEnvReport {
+        let template = app.write(".env.example", template);
+        let path = app.file(".env").to_string_lossy().into_owned();
+        fill_env_from_template(&Actor::Desktop, &template, &path, options).unwrap()
+    }
+
+    #[test]
+    fn template_variables_are_filled_by_name() {
+        let app = TestApp::new();
+        add_secret("DATABASE_URL", "postgres://db");
+
+        let report = fill(
+            &app,
+            "# Database\nDATABASE_URL=\nPORT=3000\n",
+            &WriteEnvOptions::default(),
+        );
+
+        assert_eq!(report.written, 1);
+        assert_eq!(
+            app.read(".env"),
+            "# Database\nDATABASE_URL=postgres://db\nPORT=3000\n"
+        );
+    }
+
+    #[test]
+    fn template_variables_are_filled_by_alias_and_prefix() {
+        let app = TestApp::new();
+        add_secret("OPENAI_API_KEY", "sk-1");
+        add_secret("STRIPE_KEY", "pk-1");
+        let options = WriteEnvOptions {
+            aliases: BTreeMap::from([("AI_KEY".to_string(), "OPENAI_API_KEY".to_string())]),
+            prefix: Some("VITE_".to_string()),
+            ..WriteEnvOptions::default()
+        };
+
+        let report = fill(&app, "AI_KEY=\nVITE_STRIPE_KEY=\n", &options);
+
+        assert_eq!(report.written, 2);
+        assert_eq!(app.read(".env"), "AI_KEY=sk-1\nVITE_STRIPE_KEY=pk-1\n");
+        let secrets: Vec<&str> = report.mappings.iter().map(|m| m.secret.as_str()).collect();
+        assert_eq!(secrets, ["OPENAI_API_KEY", "STRIPE_KEY"]);
+    }
+
+    #[test]
+    fn unmatched_template_variables_keep_defaults_and_are_listed() {
+        let app = TestApp::new();
+        add_secret("API_KEY", "secret");
+
+        let report = fill(
+            &app,
+            "API_KEY=changeme\nLOG_LEVEL=info\nREGION=\n",
+            &WriteEnvOptions::default(),
+        );
+
+        assert_eq!(report.missing, ["LOG_LEVEL", "REGION"]);
+        assert_eq!(
+            app.read(".env"),
+            "API_KEY=secret\nLOG_LEVEL=info\nREGION=\n"
+        );
+    }
+
+    #[test]
+    fn template_outside_the_policy_is_not_read() {
+        let app = TestApp::new();
+        let path = app.file(".env").to_string_lossy().into_owned();
+        let outside = tempfile::NamedTempFile::new().unwrap();
+        let protected = get_app_dir().join("secrets.db");
+
+        for template in [outside.path(), protected.as_path()] {
+            let result = fill_env_from_template(
+                &Actor::Desktop,
+                &template.to_string_lossy(),
+                &path,
+                &WriteEnvOptions::default(),
+            );
+            assert!(result.is_err(), "{} was read", template.display());
+        }
+        assert!(!app.file(".env").exists());
+    }
+
+    fn audit_entries(filter: AuditFilter) -> Vec<AuditEntry> {
+        query_audit_log(&filter).unwrap()
+    }
+
+    /// Run `f` and return the audit entries it added, oldest first
+    fn audited<T>(f: impl FnOnce() -> T) -> Vec<AuditEntry> {
+        let before = audit_entries(AuditFilter::default()).len();
+        f();
+        let mut entries = audit_entries(AuditFilter::default());
+        entries.truncate(entries.len() - before);
+        entries.reverse();
+        entries
+    }
+
+    fn operations(entries: &[AuditEntry]) -> Vec<&str> {
+        entries.iter().map(|e| e.operation.as_str()).collect()
+    }
+
+    #[test]
+    fn each_operation_adds_one_audit_entry() {
+        let app = TestApp::new();
+        let actor = Actor::Desktop;
+        let path = app.file(".env").to_string_lossy().into_owned();
+
+        let mut secret = None;
+        let entries = audited(|| {
+            secret = Some(create_secret(&actor, "API_KEY", None, "one", None, &[]).unwrap())
+        });
+        assert_eq!(operations(&entries), ["create_secret"]);
+        let id = secret.unwrap().id;
+
+        let keys = ["API_KEY".to_string()];
+        let steps = [
+            (
+                "update_secret",
+                audited(|| update_secret(&actor, &id, "API_KEY", None, "two", None, None)),
+            ),
+            ("get_secret", audited(|| get_secret(&actor, &id))),
+            (
+                "write_env",
+                audited(|| write_env_file(&actor, &keys, &path, &WriteEnvOptions::default())),
+            ),
+            ("delete_secret", audited(|| delete_secret(&actor, &id))),
+            ("restore_secret", audited(|| restore_secret(&actor, &id))),
+            ("delete_secret", audited(|| delete_secret(&actor, &id))),
+            ("purge_trash", audited(|| purge_trash(&actor, Some(&id)))),
+        ];
+        for (operation, entries) in steps {
+            assert_eq!(operations(&entries), [operation]);
+            assert_eq!(entries[0].secrets, ["API_KEY"]);
+            assert!(entries[0].success, "{} failed", operation);
+        }
+    }
+
+    #[test]
+    fn audit_log_filters_combine() {
+        let app = TestApp::new();
+        let cursor = Actor::Mcp("cursor".to_string());
+        let path = app.file(".env").to_string_lossy().into_owned();
+        let other = app.file("other.env").to_string_lossy().into_owned();
+        create_secret(&Actor::Desktop, "API_KEY", None, "one", None, &[]).unwrap();
+        let keys = ["API_KEY".to_string()];
+        write_env_file(&cursor, &keys, &path, &WriteEnvOptions::default()).unwrap();
+        write_env_file(&cursor, &keys, &other, &WriteEnvOptions::default()).unwrap();
+        let missing = ["NOPE".to_string()];
+        let options = WriteEnvOptions {
+            project: Some("unknown".to_string()),
+            ..WriteEnvOptions::default()
+        };
+        assert!(write_env_file(&cursor, &missing, &path, &options).is_err());
+
+        let by_mcp = audit_entries(AuditFilter {
+            actor: Some("mcp".to_string()),
+            ..AuditFilter::default()
+        });
+        assert_eq!(by_mcp.len(), 3);
+        assert!(by_mcp.iter().all(|e| e.actor == "mcp:cursor"));
+
+        let desktop = audit_entries(AuditFilter {
+            actor: Some("desktop".to_string()),
+            ..AuditFilter::default()
+        });
+        assert_eq!(operations(&desktop), ["create_secret"]);
+
+        let to_file = audit_entries(AuditFilter {
+            path: Some(path.clone()),
+            success: Some(true),
+            ..AuditFilter::default()
+        });
+        assert_eq!(to_file.len(), 1);
+        assert_eq!(to_file[0].path.as_deref(), Some(path.as_str()));
+
+        let under_dir = audit_entries(AuditFilter {
+            path: Some(format!("{}/", app.file("").display())),
+            ..AuditFilter::default()
+        });
+        assert_eq!(under_dir.len(), 3);
+
+        let failed = audit_entries(AuditFilter {
+            success: Some(false),
+            ..AuditFilter::default()
+        });
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].secrets, ["NOPE"]);
+
+        let by_secret = audit_entries(AuditFilter {
+            secret: Some("API_KEY".to_string()),
+            operation: Some("write_env".to_string()),
+            limit: Some(1),
+            ..AuditFilter::default()
+        });
+        assert_eq!(by_secret.len(), 1);
+        assert_eq!(by_secret[0].path.as_deref(), Some(other.as_str()));
+
+        let now = Utc::now().timestamp();
+        let future = audit_entries(AuditFilter {
+            since: Some(now + 60),
+            ..AuditFilter::default()
+        });
+        assert!(future.is_empty());
+        let until_now = audit_entries(AuditFilter {
+            until: Some(now + 60),
+            ..AuditFilter::default()
+        });
+        assert_eq!(until_now.len(), 4);
+    }
+
+    #[test]
+    fn audit_export_writes_matching_entries_oldest_first() {
+        let app = TestApp::new();
+        create_secret(&Actor::Desktop, "FIRST", None, "1", None, &[]).unwrap();
+        create_secret(&Actor::Desktop, "SECOND", None, "2", None, &[]).unwrap();
+        let export = app.file("audit.jsonl").to_string_lossy().into_owned();
+        let filter = AuditFilter {
+            operation: Some("create_secret".to_string()),
+            ..AuditFilter::default()
+        };
+
+        assert_eq!(export_audit_log(&filter, &export).unwrap(), 2);
+
+        let lines: Vec<AuditEntry> = app
+            .read("audit.jsonl")
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let secrets: Vec<&[String]> = lines.iter().map(|e| e.secrets.as_slice()).collect();
+        assert_eq!(secrets, [["FIRST"], ["SECOND"]]);
+        assert!(export_audit_log(&filter, "audit.jsonl").is_err());
+    }
+
+    fn dry_run(path: &str, keys: &[&str], format: Option<OutputFormat>) -> EnvDiff {
+        let keys: Vec<String> = keys.iter().map(|k| k.to_string()).collect();
+        let options = WriteEnvOptions {
+            dry_run: true,
+            format,
+            ..WriteEnvOptions::default()
+        };
+        write_env_file(&Actor::Desktop, &keys, path, &options)
+            .unwrap()
+            .diff
+            .unwrap()
+    }
+
+    #[test]
+    fn dry_run_sorts_keys_by_what_would_happen() {
+        let app = TestApp::new();
+        add_secret("SAME", "1");
+        add_secret("NEW_VALUE", "2");
+        add_secret("ADDED", "3");
+        let path = app.write(".env", "SAME=1\nNEW_VALUE=old\nOLD=gone\n");
+
+        let diff = dry_run(&path, &["SAME", "NEW_VALUE", "ADDED", "NOPE"], None);
+
+        assert_eq!(diff.unchanged, ["SAME"]);
+        assert_eq!(diff.changed, ["NEW_VALUE"]);
+        assert_eq!(diff.added, ["ADDED"]);
+        assert_eq!(diff.missing, ["NOPE"]);
+        assert_eq!(diff.removed, ["OLD"]);
+        assert!(diff.not_comparable.is_empty());
+        assert_eq!(app.read(".env"), "SAME=1\nNEW_VALUE=old\nOLD=gone\n");
+    }
+
+    #[test]
+    fn dry_run_adds_everything_to_a_new_file() {
+        let app = TestApp::new();
+        add_secret("API_KEY", "1");
+        let path = app.file("config.json").to_string_lossy().into_owned();
+
+        let diff = dry_run(&path, &["API_KEY"], None);
+
+        assert_eq!(diff.added, ["API_KEY"]);
+        assert!(!app.file("config.json").exists());
+    }
+
+    #[test]
+    fn dry_run_does_not_compare_other_formats() {
+        let app = TestApp::new();
+        add_secret("API_KEY", "1");
+        let path = app.write("config.json", "{\"API_KEY\": \"1\", \"OLD\": \"x\"}\n");
+
+        let diff = dry_run(&path, &["API_KEY", "NOPE"], Some(OutputFormat::Json));
+
+        assert_eq!(diff.not_comparable, ["API_KEY"]);
+        assert_eq!(diff.missing, ["NOPE"]);
+        assert!(diff.added.is_empty() && diff.changed.is_empty() && diff.unchanged.is_empty());
+        assert!(diff.removed.is_empty());
+    }
+
+    /// Decrypted value kept by a version
+    fn version_value(version_id: i64) -> String {
+        let blob: Vec<u8> = with_db(|conn| {
+            conn.query_row(
+                "SELECT value FROM secret_versions WHERE id = ?",
+                params![version_id],
+                |row| row.get(0),
+            )
+            .map_err(|e| e.to_string())
+        })
+        .unwrap();
+        decrypt_value(&blob).unwrap()
+    }
+
+    fn set_value(id: &str, value: &str) {
+        update_secret(&Actor::Desktop, id, "API_KEY", None, value, None, None).unwrap();
+    }
+
+    #[test]
+    fn update_keeps_the_previous_value() {
+        let _app = TestApp::new();
+        let secret = create_secret(&Actor::Desktop, "API_KEY", None, "one", None, &[]).unwrap();
+
+        set_value(&secret.id, "two");
+
+        let versions = list_secret_versions(&secret.id).unwrap();
+        assert_eq!(versions.len(), 1);
+        assert_eq!(versions[0].environment, None);
+        assert_eq!(version_value(versions[0].id), "one");
+    }
+
+    #[test]
+    fn version_retention_keeps_the_newest() {
+        let _app = TestApp::new();
+        set_version_retention(3).unwrap();
+        let secret = create_secret(&Actor::Desktop, "API_KEY", None, "v0", None, &[]).unwrap();
+
+        for i in 1..=5 {
+            set_value(&secret.id, &format!("v{}", i));
+        }
+
+        let values: Vec<String> = list_secret_versions(&secret.id)
+            .unwrap()
+            .iter()
+            .map(|version| version_value(version.id))
+            .collect();
+        assert_eq!(values, ["v4", "v3", "v2"]);
+    }
+
+    #[test]
+    fn restoring_a_version_keeps_the_replaced_value() {
+        let _app = TestApp::new();
+        let secret = create_secret(&Actor::Desktop, "API_KEY", None, "one", None, &[]).unwrap();
+        set_value(&secret.id, "two");
+        let version = list_secret_versions(&secret.id).unwrap()[0].id;
+
+        let restored = restore_secret_version(&Actor::Desktop, version).unwrap();
+
+        assert_eq!(restored.value, "one");
+        assert_eq!(
+            get_secret(&Actor::Desktop, &secret.id)
+                .unwrap()
+                .unwrap()
+                .value,
+            "one"
+        );
+        let versions = list_secret_versions(&secret.id).unwrap();
+        assert_eq!(version_value(versions[0].id), "two");
+    }
+
+    fn search_names(query: &str) -> Vec<String> {
+        search_secrets(query, None)
+            .unwrap()
+            .into_iter()
+            .map(|result| result.name)
+            .collect()
+    }
+
+    #[test]
+    fn trashed_secret_is_not_found_until_restored() {
+        let app = TestApp::new();
+        let secret = create_secret(&Actor::Desktop, "API_KEY", None, "one", None, &[]).unwrap();
+        let path = app.file(".env").to_string_lossy().into_owned();
+        let keys = ["API_KEY".to_string()];
+
+        delete_secret(&Actor::Desktop, &secret.id).unwrap();
+
+        assert!(search_names("API").is_empty());
+        let report =
+            write_env_file(&Actor::Desktop, &keys, &path, &WriteEnvOptions::default()).unwrap();
+        assert_eq!(report.missing, ["API_KEY"]);
+        assert_eq!(list_trash().unwrap()[0].name, "API_KEY");
+
+        assert!(restore_secret(&Actor::Desktop, &secret.id).unwrap());
+
+        assert_eq!(search_names("API"), ["API_KEY"]);
+        let report =
+            write_env_file(&Actor::Desktop, &keys, &path, &WriteEnvOptions::default()).unwrap();
+        assert_eq!(report.written, 1);
+        assert_eq!(app.read(".env"), "API_KEY=one\n");
+        assert!(list_trash().unwrap().is_empty());
+    }
+
+    #[test]
+    fn purged_secret_is_gone() {
+        let _app = TestApp::new();
+        let kept = create_secret(&Actor::Desktop, "KEPT", None, "1", None, &[]).unwrap();
+        let purged = create_secret(&Actor::Desktop, "PURGED", None, "2", None, &[]).unwrap();
+        delete_secret(&Actor::Desktop, &kept.id).unwrap();
+        delete_secret(&Actor::Desktop, &purged.id).unwrap();
+
+        assert_eq!(purge_trash(&Actor::Desktop, Some(&purged.id)).unwrap(), 1);
+
+        let trash: Vec<String> = list_trash().unwrap().into_iter().map(|s| s.name).collect();
+        assert_eq!(trash, ["KEPT"]);
+        assert!(!restore_secret(&Actor::Desktop, &purged.id).unwrap());
+        assert_eq!(purge_trash(&Actor::Desktop, None).unwrap(), 1);
+        assert!(list_trash().unwrap().is_empty());
+    }
+
+    #[test]
+    fn trash_retention_purges_old_entries() {
+        let _app = TestApp::new();
+        set_trash_retention_days(7).unwrap();
+        let old = create_secret(&Actor::Desktop, "OLD", None, "1", None, &[]).unwrap();
+        let recent = create_secret(&Actor::Desktop, "RECENT", None, "2", None, &[]).unwrap();
+        delete_secret(&Actor::Desktop, &old.id).unwrap();
+        delete_secret(&Actor::Desktop, &recent.id).unwrap();
+        let eight_days_ago = Utc::now().timestamp() - 8 * 24 * 60 * 60;
+        with_db(|conn| {
+            conn.execute(
+                "UPDATE secrets SET deleted_at = ? WHERE id = ?",
+                params![eight_days_ago, old.id],
+            )
+            .map_err(|e| e.to_string())
+        })
+        .unwrap();
+
+        let trash: Vec<String> = list_trash().unwrap().into_iter().map(|s| s.name).collect();
+
+        assert_eq!(trash, ["RECENT"]);
+        assert!(!restore_secret(&Actor::Desktop, &old.id).unwrap());
+    }
+
+    fn write_values(app: &TestApp, keys: &[&str], options: WriteEnvOptions) -> String {
+        let keys: Vec<String> = keys.iter().map(|k| k.to_string()).collect();
+        let path = app.file(".env").to_string_lossy().into_owned();
+        write_env_file(&Actor::Desktop, &keys, &path, &options).unwrap();
+        app.read(".env")
+    }
+
+    #[test]
+    fn project_secret_wins_over_global_one_of_the_same_name() {
+        let app = TestApp::new();
+        let project = create_project("web", None).unwrap();
+        let desktop = Actor::Desktop;
+        create_secret(&desktop, "API_KEY", None, "global", None, &[]).unwrap();
+        create_secret(&desktop, "API_KEY", None, "web", Some(&project.id), &[]).unwrap();
+        create_secret(&desktop, "SHARED", None, "shared", None, &[]).unwrap();
+
+        let in_project = WriteEnvOptions {
+            project: Some("web".to_string()),
+            ..WriteEnvOptions::default()
+        };
+        assert_eq!(
+            write_values(&app, &["API_KEY", "SHARED"], in_project),
+            "API_KEY=web\nSHARED=shared\n"
+        );
+        assert_eq!(
+            write_values(&app, &["API_KEY"], WriteEnvOptions::default()),
+            "API_KEY=global\n"
+        );
+    }
+
+    #[test]
+    fn environment_value_applies_only_to_its_environment() {
+        let app = TestApp::new();
+        let desktop = Actor::Desktop;
+        let stripe = create_secret(&desktop, "STRIPE_KEY", None, "sk_test", None, &[]).unwrap();
+        create_secret(&desktop, "REGION", None, "eu", None, &[]).unwrap();
+        set_secret_environment_value(&desktop, &stripe.id, "staging", "sk_staging").unwrap();
+
+        let in_environment = |environment: &str| WriteEnvOptions {
+            environment: Some(environment.to_string()),
+            ..WriteEnvOptions::default()
+        };
+        let keys = ["STRIPE_KEY", "REGION"];
+        assert_eq!(
+            write_values(&app, &keys, in_environment("staging")),
+            "STRIPE_KEY=sk_staging\nREGION=eu\n"
+        );
+        assert_eq!(
+            write_values(&app, &keys, in_environment("production")),
+            "STRIPE_KEY=sk_test\nREGION=eu\n"
+        );
+        assert_eq!(
+            write_values(&app, &keys, WriteEnvOptions::default()),
+            "STRIPE_KEY=sk_test\nREGION=eu\n"
+        );
+    }
+
+    fn tags(tags: &[&str]) -> Vec<String> {
+        tags.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn tags_are_trimmed_lowercased_deduplicated_and_sorted() {
+        assert_eq!(
+            normalize_tags(&tags(&[" AWS ", "prod", "", "aws", "Billing"])).unwrap(),
+            ["aws", "billing", "prod"]
+        );
+        for invalid in ["two words", "a,b", "tab\there"] {
+            assert!(normalize_tags(&tags(&[invalid])).is_err(), "{}", invalid);
+        }
+        assert_eq!(normalize_tag(" Prod ").unwrap(), "prod");
+        assert!(normalize_tag("  ").is_err());
+    }
+
+    #[test]
+    fn tag_terms_filter_search_results() {
+        let _app = TestApp::new();
+        let desktop = Actor::Desktop;
+        create_secret(
+            &desktop,
+            "AWS_KEY",
+            None,
+            "1",
+            None,
+            &tags(&["aws", "prod"]),
+        )
+        .unwrap();
+        create_secret(&desktop, "AWS_DEV_KEY", None, "2", None, &tags(&["aws"])).unwrap();
+        create_secret(&desktop, "STRIPE_KEY", None, "3", None, &tags(&["prod"])).unwrap();
+
+        assert_eq!(search_names("tag:aws"), ["AWS_DEV_KEY", "AWS_KEY"]);
+        assert_eq!(search_names("tag:AWS tag:prod"), ["AWS_KEY"]);
+        assert_eq!(search_names("tag:prod stripe"), ["STRIPE_KEY"]);
+        assert!(search_names("tag:missing").is_empty());
+    }
+
+    #[test]
+    fn tag_selects_secrets_to_write_after_the_keys() {
+        let app = TestApp::new();
+        let desktop = Actor::Desktop;
+        create_secret(&desktop, "A_KEY", None, "a", None, &tags(&["web"])).unwrap();
+        create_secret(&desktop, "B_KEY", None, "b", None, &tags(&["web"])).unwrap();
+        create_secret(&desktop, "OTHER", None, "o", None, &[]).unwrap();
+
+        let options = WriteEnvOptions {
+            tag: Some(" Web ".to_string()),
+            prefix: Some("VITE_".to_string()),
+            ..WriteEnvOptions::default()
+        };
+        assert_eq!(
+            write_values(&app, &["OTHER", "B_KEY"], options),
+            "VITE_OTHER=o\nVITE_B_KEY=b\nVITE_A_KEY=a\n"
+        );
+
+        let path = app.file(".env").to_string_lossy().into_owned();
+        let spaced = WriteEnvOptions {
+            tag: Some("web prod".to_string()),
+            ..WriteEnvOptions::default()
+        };
+        assert!(write_env_file(&desktop, &[], &path, &spaced).is_err());
+    }
+
+    /// Later duplicates of an updated key are dropped, as loaders disagree on
+    /// which one wins
+    #[test]
+    fn merge_updates_assignments_in_place() {
+        let app = TestApp::new();
+        add_secret("API_KEY", "new-key");
+        add_secret("DB_URL", "postgres://new");
+        add_secret("ADDED", "added");
+        app.write(
+            ".env",
+            "# Service config\n\
+             export API_KEY=old-key # rotated monthly\n\
+             UNRELATED='keep me'\n\
+             \n\
+             DB_URL=postgres://old\n\
+             DB_URL=postgres://shadowed\n",
+        );
+
+        let merge = WriteEnvOptions {
+            mode: WriteMode::Merge,
+            ..WriteEnvOptions::default()
+        };
+        assert_eq!(
+            write_values(&app, &["API_KEY", "DB_URL", "ADDED"], merge),
+            "# Service config\n\
+             export API_KEY=new-key # rotated monthly\n\
+             UNRELATED='keep me'\n\
+             \n\
+             DB_URL=postgres://new\n\
+             ADDED=added\n"
+        );
+    }
+
+    fn backup_files() -> Vec<PathBuf> {
+        std::fs::read_dir(get_env_backup_dir())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect()
+    }
+
+    #[test]
+    fn backups_are_encrypted() {
+        let app = TestApp::new();
+        add_secret("API_KEY", "new");
+        app.write(".env", "API_KEY=plaintext-secret-value\n");
+
+        write_values(&app, &["API_KEY"], WriteEnvOptions::default());
+
+        let files = backup_files();
+        assert_eq!(files.len(), 1);
+        let stored = std::fs::read(&files[0]).unwrap();
+        let plaintext = b"plaintext-secret-value";
+        assert!(!stored.windows(plaintext.len()).any(|w| w == plaintext));
+        assert_eq!(
+            decrypt_value(&stored).unwrap(),
+            "API_KEY=plaintext-secret-value\n"
+        );
+    }
+
+    #[test]
+    fn backup_retention_keeps_the_newest() {
+        let app = TestApp::new();
+        set_env_backup_retention(2).unwrap();
+        let secret = create_secret(&Actor::Desktop, "API_KEY", None, "v0", None, &[]).unwrap();
+        write_values(&app, &["API_KEY"], WriteEnvOptions::default());
+
+        for i in 1..=4 {
+            set_value(&secret.id, &format!("v{}", i));
+            write_values(&app, &["API_KEY"], WriteEnvOptions::default());
+        }
+
+        let path = app.file(".env").to_string_lossy().into_owned();
+        let backups = list_env_backups(Some(&path)).unwrap();
+        assert_eq!(backups.len(), 2);
+        assert_eq!(backup_files().len(), 2);
+        let mut contents: Vec<String> = backup_files()
+            .iter()
+            .map(|file| decrypt_value(&std::fs::read(file).unwrap()).unwrap())
+            .collect();
+        contents.sort();
+        assert_eq!(contents, ["API_KEY=v2\n", "API_KEY=v3\n"]);
+    }
+
+    #[test]
+    fn restoring_a_backup_writes_back_the_exact_bytes() {
+        let app = TestApp::new();
+        add_secret("API_KEY", "new");
+        let original =
+            "# Windows line endings\r\nAPI_KEY=old\r\nNOTE=\u{e9}t\u{e9}  # no final newline";
+        app.write(".env", original);
+        write_values(&app, &["API_KEY"], WriteEnvOptions::default());
+        let path = app.file(".env").to_string_lossy().into_owned();
+        let backup = list_env_backups(Some(&path)).unwrap()[0].id;
+
+        restore_env_backup(&Actor::Desktop, backup, GitPolicy::Refuse).unwrap();
+
+        assert_eq!(
+            std::fs::read(app.file(".env")).unwrap(),
+            original.as_bytes()
+        );
+        // What the restore replaced is kept too
+        assert_eq!(list_env_backups(Some(&path)).unwrap().len(), 2);
+    }
+
+    fn aliases(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(variable, secret)| (variable.to_string(), secret.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn aliases_write_a_secret_under_more_names() {
+        let _app = TestApp::new();
+        add_secret("OPENAI_API_KEY", "sk");
+        let options = WriteEnvOptions {
+            aliases: aliases(&[("VITE_OPENAI_KEY", "OPENAI_API_KEY"), ("GONE", "NOPE")]),
+            ..WriteEnvOptions::default()
+        };
+
+        let resolved = resolve_env_values(&["OPENAI_API_KEY".to_string()], &options).unwrap();
+
+        assert_eq!(
+            resolved.values,
+            [
+                ("OPENAI_API_KEY".to_string(), "sk".to_string()),
+                ("VITE_OPENAI_KEY".to_string(), "sk".to_string()),
+            ]
+        );
+        assert_eq!(resolved.missing, ["NOPE"]);
+        let found: Vec<(&str, bool)> = resolved
+            .mappings
+            .iter()
+            .map(|m| (m.variable.as_str(), m.found))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("OPENAI_API_KEY", true),
+                ("GONE", false),
+                ("VITE_OPENAI_KEY", true)
+            ]
+        );
+    }
+
+    #[test]
+    fn prefix_applies_to_keys_but_not_aliases() {
+        let _app = TestApp::new();
+        let key = ["API_KEY".to_string()];
+        add_secret("API_KEY", "a");
+        let options = WriteEnvOptions {
+            prefix: Some("NEXT_PUBLIC_".to_string()),
+            aliases: aliases(&[("PLAIN", "API_KEY")]),
+            ..WriteEnvOptions::default()
+        };
+
+        let resolved = resolve_env_values(&key, &options).unwrap();
+
+        let variables: Vec<&str> = resolved.values.iter().map(|(v, _)| v.as_str()).collect();
+        assert_eq!(variables, ["NEXT_PUBLIC_API_KEY", "PLAIN"]);
+
+        let invalid = WriteEnvOptions {
+            prefix: Some("1BAD ".to_string()),
+            ..WriteEnvOptions::default()
+        };
+        assert!(resolve_env_values(&key, &invalid).is_err());
+    }
+
+    #[test]
+    fn one_variable_cannot_take_two_secrets() {
+        let _app = TestApp::new();
+        let key = ["API_KEY".to_string()];
+        add_secret("API_KEY", "a");
+        add_secret("OTHER_KEY", "b");
+        let options = WriteEnvOptions {
+            aliases: aliases(&[("API_KEY", "OTHER_KEY")]),
+            ..WriteEnvOptions::default()
+        };
+
+        let error = resolve_env_values(&key, &options).err();
+        assert_eq!(
+            error.as_deref(),
+            Some("API_KEY is mapped to both API_KEY and OTHER_KEY")
+        );
+
+        // Mapping a variable to the secret it already names is no conflict
+        let same = WriteEnvOptions {
+            aliases: aliases(&[("API_KEY", "API_KEY")]),
+            ..WriteEnvOptions::default()
+        };
+        let resolved = resolve_env_values(&key, &same).unwrap();
+        assert_eq!(resolved.values.len(), 1);
+    }
+
+    #[test]
+    fn value_operations_fail_with_the_locked_error() {
+        let app = TestApp::new();
+        let secret = create_secret(&Actor::Desktop, "API_KEY", None, "one", None, &[]).unwrap();
+        let path = app.file(".env").to_string_lossy().into_owned();
+        vault::lock().unwrap();
+
+        let desktop = &Actor::Desktop;
+        let errors = [
+            get_secret(desktop, &secret.id).err(),
+            create_secret(desktop, "NEW", None, "v", None, &[]).err(),
+            update_secret(desktop, &secret.id, "API_KEY", None, "two", None, None).err(),
+            set_secret_environment_value(desktop, &secret.id, "staging", "s").err(),
+            write_env_file(
+                desktop,
+                &tags(&["API_KEY"]),
+                &path,
+                &WriteEnvOptions::default(),
+            )
+            .err(),
+        ];
+        for error in errors {
+            assert_eq!(error.as_deref(), Some(vault::VAULT_LOCKED));
+        }
+        assert!(!app.file(".env").exists());
+        // Names are not secret
+        assert_eq!(search_names("API"), ["API_KEY"]);
+    }
+
+    #[test]
+    fn migrated_plaintext_leaves_no_trace_in_the_file() {
+        let _app = TestApp::new();
+        // Long enough to spill onto overflow pages, which are freed unwiped
+        let plaintext = "legacy-plaintext-value ".repeat(400);
+        let secret = create_secret(&Actor::Desktop, "API_KEY", None, "v", None, &[]).unwrap();
+        with_db(|conn| {
+            conn.execute(
+                "UPDATE secrets SET value = ? WHERE id = ?",
+                params![&plaintext, secret.id],
+            )
+            .map_err(|e| e.to_string())
+        })
+        .unwrap();
+        let on_disk = || {
+            let bytes = std::fs::read(get_db_path()).unwrap();
+            bytes.windows(22).any(|w| w == b"legacy-plaintext-value")
+        };
+        assert!(on_disk());
+
+        with_db(encrypt_plaintext_values).unwrap();
+
+        assert!(!on_disk());
+        assert_eq!(
+            get_secret(&Actor::Desktop, &secret.id)
+                .unwrap()
+                .unwrap()
+                .value,
+            plaintext
+        );
+    }
 }
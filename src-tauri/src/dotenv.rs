@@ -0,0 +1,447 @@
+use std::fmt;
+
+/// One line (or multi-line quoted entry) of a dotenv file
+#[derive(Debug, Clone)]
+enum Line {
+    /// `KEY=value` assignment, with its original text
+    Entry {
+        key: String,
+        value: String,
+        /// Comment after the value, with the whitespace before it
+        comment: String,
+        raw: String,
+    },
+    /// Comment, blank line or anything else, kept verbatim
+    Other(String),
+}
+
+/// Parsed dotenv file that renders back to its original text unless edited
+#[derive(Debug, Clone, Default)]
+pub struct Document {
+    lines: Vec<Line>,
+}
+
+/// Parse dotenv content. Never fails: lines that are not assignments are
+/// kept as-is so they survive a merge.
+pub fn parse(content: &str) -> Document {
+    let mut lines = Vec::new();
+    let mut rest = content;
+
+    while !rest.is_empty() {
+        let (line, consumed) = parse_line(rest);
+        lines.push(line);
+        rest = &rest[consumed..];
+    }
+
+    Document { lines }
+}
+
+impl Document {
+    /// Assignments in file order, including duplicates
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.lines.iter().filter_map(|line| match line {
+            Line::Entry { key, value, .. } => Some((key.as_str(), value.as_str())),
+            Line::Other(_) => None,
+        })
+    }
+
+    /// Set a key in place, keeping its position, any `export` prefix and
+    /// any trailing comment, or append it at the end. A line that already holds the value is left
+    /// untouched, and later duplicates of the key are removed.
+    pub fn set(&mut self, key: &str, value: &str) {
+        let mut found = false;
+
+        self.lines.retain_mut(|line| {
+            let Line::Entry {
+                key: k,
+                value: v,
+                comment,
+                raw,
+            } = line
+            else {
+                return true;
+            };
+            if k != key {
+                return true;
+            }
+            if found {
+                return false;
+            }
+
+            found = true;
+            if v == value {
+                return true;
+            }
+            let exported = raw
+                .trim_start()
+                .strip_prefix("export")
+                .is_some_and(|after| after.starts_with([' ', '\t']));
+            let export = if exported { "export " } else { "" };
+            *raw = format!("{}{}{}\n", export, format_line(key, value), comment);
+            *v = value.to_string();
+            true
+        });
+
+        if !found {
+            if let Some(Line::Entry { raw, .. } | Line::Other(raw)) = self.lines.last_mut() {
+                if !raw.ends_with('\n') {
+                    raw.push('\n');
+                }
+            }
+            self.lines.push(Line::Entry {
+                key: key.to_string(),
+                value: value.to_string(),
+                comment: String::new(),
+                raw: format!("{}\n", format_line(key, value)),
+            });
+        }
+    }
+}
+
+impl fmt::Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in &self.lines {
+            match line {
+                Line::Entry { raw, .. } | Line::Other(raw) => f.write_str(raw)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Format a `KEY=value` assignment (without a trailing newline)
+pub fn format_line(key: &str, value: &str) -> String {
+    format!("{}={}", key, serialize_value(value))
+}
+
+/// Serialize a value so that `parse` reads back exactly the same string.
+/// Plain values stay bare; values without `'` or control characters are
+/// single-quoted, which loaders treat literally (no `$` expansion), unless a
+/// `\\` or a trailing `\` would be unescaped by loaders that follow
+/// python-dotenv; everything else is double-quoted with `\`, `"`, `$`,
+/// `` ` ``, newlines, carriage returns and tabs escaped.
+pub fn serialize_value(value: &str) -> String {
+    if value.chars().all(is_bare) {
+        return value.to_string();
+    }
+
+    let literal = !value.contains(|c: char| c == '\'' || c.is_control())
+        && !value.contains("\\\\")
+        && !value.ends_with('\\');
+    if literal {
+        return format!("'{}'", value);
+    }
+
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            '\\' | '"' | '$' | '`' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Characters that need no quoting in any dotenv dialect
+fn is_bare(c: char) -> bool {
+    c.is_ascii_alphanumeric()
+        || matches!(c, '_' | '-' | '.' | ',' | '/' | ':' | '@' | '%' | '+' | '=')
+}
+
+/// Parse the line starting at `input`, returning it and the bytes consumed
+/// (a quoted value may continue over several lines)
+fn parse_line(input: &str) -> (Line, usize) {
+    let line_end = input.find('\n').map_or(input.len(), |i| i + 1);
+    let line = &input[..line_end];
+
+    let Some((key, value_start)) = parse_key(line) else {
+        return (Line::Other(line.to_string()), line_end);
+    };
+
+    let (value, comment, end) = match parse_quoted(&input[value_start..]) {
+        Some((value, len)) => {
+            // Anything after the closing quote (usually a comment) belongs to the entry
+            let value_end = value_start + len;
+            let end = input[value_end..]
+                .find('\n')
+                .map_or(input.len(), |i| value_end + i + 1);
+            let rest = input[value_end..end].trim_end_matches(['\n', '\r']);
+            let comment = match rest.trim_start_matches([' ', '\t']) {
+                trimmed if !trimmed.starts_with('#') => String::new(),
+                _ if rest.starts_with([' ', '\t']) => rest.to_string(),
+                // A bare value written in its place would run into it
+                _ => format!(" {}", rest),
+            };
+            (value, comment, end)
+        }
+        None => {
+            let (value, comment) = parse_unquoted(&line[value_start..]);
+            (value, comment, line_end)
+        }
+    };
+
+    let entry = Line::Entry {
+        key,
+        value,
+        comment,
+        raw: input[..end].to_string(),
+    };
+    (entry, end)
+}
+
+/// Parse `[export] KEY =`, returning the key and the offset after `=`
+fn parse_key(line: &str) -> Option<(String, usize)> {
+    let mut rest = line.trim_start();
+    if let Some(after) = rest.strip_prefix("export") {
+        if after.starts_with([' ', '\t']) {
+            rest = after.trim_start();
+        }
+    }
+
+    let key_len = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-')))
+        .unwrap_or(rest.len());
+    let key = &rest[..key_len];
+    if key.is_empty() || key.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let after_key = rest[key_len..].trim_start_matches([' ', '\t']);
+    let after_eq = after_key.strip_prefix('=')?;
+    Some((key.to_string(), line.len() - after_eq.len()))
+}
+
+/// Parse a single- or double-quoted value, returning it and the bytes
+/// consumed including the closing quote. None if unquoted or unterminated.
+fn parse_quoted(input: &str) -> Option<(String, usize)> {
+    let trimmed = input.trim_start_matches([' ', '\t']);
+    let offset = input.len() - trimmed.len();
+
+    if let Some(body) = trimmed.strip_prefix('\'') {
+        // Single quotes are literal but for `\\` and `\'`, as in python-dotenv
+        let mut value = String::new();
+        let mut chars = body.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\'' => return Some((value, offset + 1 + i + 1)),
+                '\\' => match chars.next() {
+                    Some((_, escaped @ ('\\' | '\''))) => value.push(escaped),
+                    Some((_, other)) => {
+                        value.push('\\');
+                        value.push(other);
+                    }
+                    None => return None,
+                },
+                _ => value.push(c),
+            }
+        }
+        return None;
+    }
+
+    let body = trimmed.strip_prefix('"')?;
+    let mut value = String::new();
+    let mut chars = body.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, offset + 1 + i + 1)),
+            '\\' => match chars.next() {
+                Some((_, 'n')) => value.push('\n'),
+                Some((_, 'r')) => value.push('\r'),
+                Some((_, 't')) => value.push('\t'),
+                Some((_, escaped @ ('\\' | '"' | '$' | '`'))) => value.push(escaped),
+                Some((_, other)) => {
+                    value.push('\\');
+                    value.push(other);
+                }
+                None => return None,
+            },
+            _ => value.push(c),
+        }
+    }
+    None
+}
+
+/// Parse an unquoted value: the rest of the line up to an inline comment.
+/// Returns the value and the comment, with the whitespace before it.
+fn parse_unquoted(line: &str) -> (String, String) {
+    let value = line.trim_end_matches(['\n', '\r']);
+    let value = value.trim_start_matches([' ', '\t']);
+
+    let comment = value
+        .char_indices()
+        .find(|&(i, c)| c == '#' && (i == 0 || value[..i].ends_with([' ', '\t'])))
+        .map_or(value.len(), |(i, _)| i);
+    let (value, comment) = value.split_at(comment);
+    let trimmed = value.trim_end();
+    let comment = match &value[trimmed.len()..] {
+        _ if comment.is_empty() => String::new(),
+        // `KEY=#comment`: keep it apart from the new value
+        "" => format!(" {}", comment),
+        space => format!("{}{}", space, comment),
+    };
+    (trimmed.to_string(), comment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn entries(document: &Document) -> Vec<(String, String)> {
+        document
+            .entries()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn round_trip(value: &str) -> Vec<(String, String)> {
+        entries(&parse(&format!("{}\n", format_line("KEY", value))))
+    }
+
+    #[test]
+    fn chooses_quoting() {
+        assert_eq!(serialize_value("sk-test_123"), "sk-test_123");
+        assert_eq!(serialize_value(""), "");
+        assert_eq!(serialize_value("has space"), "'has space'");
+        assert_eq!(serialize_value("$HOME#x"), "'$HOME#x'");
+        assert_eq!(serialize_value("it's"), "\"it's\"");
+        assert_eq!(serialize_value("a\\\"$HOME"), "'a\\\"$HOME'");
+        assert_eq!(serialize_value("C:\\\\share"), "\"C:\\\\\\\\share\"");
+        assert_eq!(serialize_value("dir\\"), "\"dir\\\\\"");
+        assert_eq!(serialize_value("it's $HOME"), "\"it's \\$HOME\"");
+        assert_eq!(
+            serialize_value("-----BEGIN KEY-----\nabc\n-----END KEY-----"),
+            "\"-----BEGIN KEY-----\\nabc\\n-----END KEY-----\""
+        );
+    }
+
+    #[test]
+    fn parses_common_forms() {
+        let document = parse(
+            "# comment\n\
+             export A=1\n\
+             B = two words # note\n\
+             C='single $HOME'\n\
+             D=\"line\\nbreak\" # note\n\
+             E=\"real\n\
+             newline\"\n\
+             not an assignment\n",
+        );
+        assert_eq!(
+            entries(&document),
+            [
+                ("A", "1"),
+                ("B", "two words"),
+                ("C", "single $HOME"),
+                ("D", "line\nbreak"),
+                ("E", "real\nnewline"),
+            ]
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+        );
+    }
+
+    #[test]
+    fn single_quotes_unescape_like_python_dotenv() {
+        let document = parse(
+            "A='it\\'s'\n\
+             B='C:\\\\share\\dir'\n\
+             C='a\\nb'\n\
+             D='ends\\\\' # note\n",
+        );
+        assert_eq!(
+            entries(&document),
+            [
+                ("A", "it's"),
+                ("B", "C:\\share\\dir"),
+                ("C", "a\\nb"),
+                ("D", "ends\\"),
+            ]
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+        );
+    }
+
+    #[test]
+    fn unchanged_document_renders_verbatim() {
+        let content = "# c\nexport A=1 # x\r\nB=\"multi\nline\"\n\njunk\nC='open";
+        assert_eq!(parse(content).to_string(), content);
+    }
+
+    #[test]
+    fn set_keeps_trailing_comments() {
+        let mut document = parse(
+            "A=old # unquoted\n\
+             export B='old'   # quoted\n\
+             C=#empty\n\
+             D=\"old\"#tight\n\
+             E=old   \n",
+        );
+        for key in ["A", "B", "C", "D", "E"] {
+            document.set(key, "new value");
+        }
+        assert_eq!(
+            document.to_string(),
+            "A='new value' # unquoted\n\
+             export B='new value'   # quoted\n\
+             C='new value' #empty\n\
+             D='new value' #tight\n\
+             E='new value'\n"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips_arbitrary_bytes(bytes in proptest::collection::vec(any::<u8>(), 0..256)) {
+            let value = String::from_utf8_lossy(&bytes).into_owned();
+            prop_assert_eq!(round_trip(&value), vec![("KEY".to_string(), value)]);
+        }
+
+        #[test]
+        fn round_trips_arbitrary_strings(value in any::<String>()) {
+            prop_assert_eq!(round_trip(&value), vec![("KEY".to_string(), value)]);
+        }
+
+        #[test]
+        fn round_trips_backslashes_and_quotes(value in "[a\\\\'\" $]{0,12}") {
+            prop_assert_eq!(round_trip(&value), vec![("KEY".to_string(), value)]);
+        }
+
+        #[test]
+        fn round_trips_documents(
+            values in proptest::collection::vec(any::<String>(), 1..8),
+            merged in any::<String>(),
+        ) {
+            let mut document = Document::default();
+            for (i, value) in values.iter().enumerate() {
+                document.set(&format!("KEY_{}", i), value);
+            }
+            let mut reparsed = parse(&document.to_string());
+            reparsed.set("KEY_0", &merged);
+
+            let mut expected: Vec<(String, String)> = values
+                .into_iter()
+                .enumerate()
+                .map(|(i, value)| (format!("KEY_{}", i), value))
+                .collect();
+            expected[0].1 = merged;
+            prop_assert_eq!(entries(&parse(&reparsed.to_string())), expected);
+        }
+
+        #[test]
+        fn set_beside_a_comment_round_trips(value in any::<String>()) {
+            let mut document = parse("KEY=old # note\n");
+            document.set("KEY", &value);
+
+            let text = document.to_string();
+            prop_assert!(text.ends_with(" # note\n"));
+            prop_assert_eq!(entries(&parse(&text)), vec![("KEY".to_string(), value)]);
+        }
+    }
+}
@@ -0,0 +1,766 @@
+use crate::dotenv;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Placeholder written instead of values in SealedSecret manifests
+const SEALED_PLACEHOLDER: &str = "REPLACE_WITH_KUBESEAL_OUTPUT";
+
+/// File format `write_env_file` can produce
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// `KEY=value` lines read by dotenv loaders
+    Dotenv,
+    /// POSIX shell script of `export KEY='value'` lines
+    Shell,
+    /// Flat JSON object
+    Json,
+    /// Flat YAML mapping
+    Yaml,
+    /// Flat TOML table
+    Toml,
+    /// File for `docker run --env-file` (values are taken literally)
+    Docker,
+    /// systemd `EnvironmentFile=`
+    Systemd,
+    /// direnv `.envrc`
+    Direnv,
+    /// Kubernetes `v1/Secret` manifest with base64 values under `data:`
+    Kubernetes,
+    /// `SealedSecret` manifest skeleton listing the keys, without values,
+    /// to be filled in with `kubeseal`
+    SealedSecret,
+}
+
+/// Metadata for Kubernetes manifests
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KubernetesOptions {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub labels: BTreeMap<String, String>,
+}
+
+/// Renders secrets in one output format
+pub trait Formatter {
+    /// Render `(name, value)` pairs, in order, as the whole file content
+    fn render(&self, entries: &[(String, String)]) -> Result<String, String>;
+}
+
+impl OutputFormat {
+    /// Name used in tool arguments and stored with write approvals
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OutputFormat::Dotenv => "dotenv",
+            OutputFormat::Shell => "shell",
+            OutputFormat::Json => "json",
+            OutputFormat::Yaml => "yaml",
+            OutputFormat::Toml => "toml",
+            OutputFormat::Docker => "docker",
+            OutputFormat::Systemd => "systemd",
+            OutputFormat::Direnv => "direnv",
+            OutputFormat::Kubernetes => "kubernetes",
+            OutputFormat::SealedSecret => "sealedsecret",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "dotenv" => Ok(OutputFormat::Dotenv),
+            "shell" => Ok(OutputFormat::Shell),
+            "json" => Ok(OutputFormat::Json),
+            "yaml" => Ok(OutputFormat::Yaml),
+            "toml" => Ok(OutputFormat::Toml),
+            "docker" => Ok(OutputFormat::Docker),
+            "systemd" => Ok(OutputFormat::Systemd),
+            "direnv" => Ok(OutputFormat::Direnv),
+            "kubernetes" => Ok(OutputFormat::Kubernetes),
+            "sealedsecret" => Ok(OutputFormat::SealedSecret),
+            _ => Err(format!("Invalid output format: {}", value)),
+        }
+    }
+
+    /// Guess the format from the target file name, defaulting to dotenv
+    pub fn infer(path: &Path) -> OutputFormat {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        let extension = path
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        match (file_name.as_str(), extension.as_str()) {
+            (".envrc", _) => OutputFormat::Direnv,
+            (_, "json") => OutputFormat::Json,
+            (_, "yaml" | "yml") => OutputFormat::Yaml,
+            (_, "toml") => OutputFormat::Toml,
+            (_, "sh" | "bash" | "zsh") => OutputFormat::Shell,
+            _ => OutputFormat::Dotenv,
+        }
+    }
+
+    /// Get the formatter for this format. Kubernetes formats need `kubernetes`.
+    pub fn formatter(
+        self,
+        kubernetes: Option<&KubernetesOptions>,
+    ) -> Result<Box<dyn Formatter>, String> {
+        let formatter: Box<dyn Formatter> = match self {
+            OutputFormat::Dotenv => Box::new(DotenvFormatter),
+            OutputFormat::Shell => Box::new(ShellFormatter {
+                header: "#!/bin/sh",
+            }),
+            OutputFormat::Json => Box::new(JsonFormatter),
+            OutputFormat::Yaml => Box::new(YamlFormatter),
+            OutputFormat::Toml => Box::new(TomlFormatter),
+            OutputFormat::Docker => Box::new(DockerFormatter),
+            OutputFormat::Systemd => Box::new(SystemdFormatter),
+            OutputFormat::Direnv => Box::new(ShellFormatter {
+                header: "# Loaded by direnv",
+            }),
+            OutputFormat::Kubernetes | OutputFormat::SealedSecret => {
+                let options = kubernetes
+                    .ok_or("Kubernetes output needs a secret name")?
+                    .clone();
+                ensure_kubernetes_name(&options.name)?;
+                if let Some(namespace) = &options.namespace {
+                    ensure_kubernetes_namespace(namespace)?;
+                }
+                Box::new(KubernetesFormatter {
+                    options,
+                    sealed: self == OutputFormat::SealedSecret,
+                })
+            }
+        };
+        Ok(formatter)
+    }
+}
+
+struct DotenvFormatter;
+
+impl Formatter for DotenvFormatter {
+    fn render(&self, entries: &[(String, String)]) -> Result<String, String> {
+        Ok(entries
+            .iter()
+            .map(|(name, value)| format!("{}\n", dotenv::format_line(name, value)))
+            .collect())
+    }
+}
+
+/// `export` lines for shell scripts and direnv
+struct ShellFormatter {
+    header: &'static str,
+}
+
+impl Formatter for ShellFormatter {
+    fn render(&self, entries: &[(String, String)]) -> Result<String, String> {
+        let mut content = format!("{}\n", self.header);
+        for (name, value) in entries {
+            ensure_shell_name(name)?;
+            // Single quotes are literal; a quote closes, escapes and reopens
+            content.push_str(&format!(
+                "export {}='{}'\n",
+                name,
+                value.replace('\'', r"'\''")
+            ));
+        }
+        Ok(content)
+    }
+}
+
+struct JsonFormatter;
+
+impl Formatter for JsonFormatter {
+    fn render(&self, entries: &[(String, String)]) -> Result<String, String> {
+        // Built by hand to keep the requested key order
+        let fields: Vec<String> = entries
+            .iter()
+            .map(|(name, value)| format!("  {}: {}", json_string(name), json_string(value)))
+            .collect();
+
+        if fields.is_empty() {
+            return Ok("{}\n".to_string());
+        }
+        Ok(format!("{{\n{}\n}}\n", fields.join(",\n")))
+    }
+}
+
+struct YamlFormatter;
+
+impl Formatter for YamlFormatter {
+    fn render(&self, entries: &[(String, String)]) -> Result<String, String> {
+        if entries.is_empty() {
+            return Ok("{}\n".to_string());
+        }
+
+        // YAML double-quoted scalars accept JSON string escapes; quoting keys
+        // too keeps names like ON or NO from being read as booleans
+        Ok(entries
+            .iter()
+            .map(|(name, value)| format!("{}: {}\n", yaml_string(name), yaml_string(value)))
+            .collect())
+    }
+}
+
+struct TomlFormatter;
+
+impl Formatter for TomlFormatter {
+    fn render(&self, entries: &[(String, String)]) -> Result<String, String> {
+        Ok(entries
+            .iter()
+            .map(|(name, value)| {
+                let bare = !name.is_empty()
+                    && name
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'));
+                let key = if bare {
+                    name.clone()
+                } else {
+                    toml_string(name)
+                };
+                format!("{} = {}\n", key, toml_string(value))
+            })
+            .collect())
+    }
+}
+
+/// Docker reads everything after `=` literally, so values cannot be quoted
+/// and cannot span lines
+struct DockerFormatter;
+
+impl Formatter for DockerFormatter {
+    fn render(&self, entries: &[(String, String)]) -> Result<String, String> {
+        let mut content = String::new();
+        for (name, value) in entries {
+            // Docker rejects names with whitespace; `#` would start a comment
+            let valid = !name.is_empty()
+                && !name.starts_with('#')
+                && !name.contains(|c: char| c == '=' || c.is_whitespace());
+            if !valid {
+                return Err(format!("{} is not a valid Docker variable name", name));
+            }
+            if value.contains(['\n', '\r']) {
+                return Err(format!(
+                    "Docker env files cannot hold multi-line values ({})",
+                    name
+                ));
+            }
+            content.push_str(&format!("{}={}\n", name, value));
+        }
+        Ok(content)
+    }
+}
+
+/// systemd unquotes double-quoted values and processes `\\`, `\"`, `\$`
+/// and `` \` ``; a newline would end the assignment
+struct SystemdFormatter;
+
+impl Formatter for SystemdFormatter {
+    fn render(&self, entries: &[(String, String)]) -> Result<String, String> {
+        let mut content = String::new();
+        for (name, value) in entries {
+            ensure_shell_name(name)?;
+            if value.contains(['\n', '\r']) {
+                return Err(format!(
+                    "systemd environment files cannot hold multi-line values ({})",
+                    name
+                ));
+            }
+            let mut escaped = String::with_capacity(value.len());
+            for c in value.chars() {
+                if matches!(c, '\\' | '"' | '$' | '`') {
+                    escaped.push('\\');
+                }
+                escaped.push(c);
+            }
+            content.push_str(&format!("{}=\"{}\"\n", name, escaped));
+        }
+        Ok(content)
+    }
+}
+
+struct KubernetesFormatter {
+    options: KubernetesOptions,
+    sealed: bool,
+}
+
+impl Formatter for KubernetesFormatter {
+    fn render(&self, entries: &[(String, String)]) -> Result<String, String> {
+        let mut data = Vec::new();
+        for (name, value) in entries {
+            let valid = !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+            if !valid {
+                return Err(format!("{} is not a valid Kubernetes Secret key", name));
+            }
+            // Sealed manifests never contain values, only placeholders
+            let value = if self.sealed {
+                SEALED_PLACEHOLDER.to_string()
+            } else {
+                BASE64.encode(value)
+            };
+            data.push((name.as_str(), value));
+        }
+
+        let mut content = String::new();
+        if self.sealed {
+            content.push_str("# Replace each placeholder with the output of `kubeseal --raw`\n");
+            content.push_str("apiVersion: bitnami.com/v1alpha1\nkind: SealedSecret\n");
+            content.push_str(&self.metadata(0));
+            content.push_str("spec:\n  encryptedData:");
+            push_mapping(&mut content, &data, 4);
+            content.push_str("  template:\n");
+            content.push_str(&self.metadata(4));
+            content.push_str("    type: Opaque\n");
+        } else {
+            content.push_str("apiVersion: v1\nkind: Secret\n");
+            content.push_str(&self.metadata(0));
+            content.push_str("type: Opaque\ndata:");
+            push_mapping(&mut content, &data, 2);
+        }
+        Ok(content)
+    }
+}
+
+impl KubernetesFormatter {
+    /// `metadata:` block indented by `indent` spaces
+    fn metadata(&self, indent: usize) -> String {
+        let pad = " ".repeat(indent);
+        let mut metadata = format!(
+            "{pad}metadata:\n{pad}  name: {}\n",
+            yaml_string(&self.options.name)
+        );
+        if let Some(namespace) = &self.options.namespace {
+            metadata.push_str(&format!("{pad}  namespace: {}\n", yaml_string(namespace)));
+        }
+        if !self.options.labels.is_empty() {
+            metadata.push_str(&format!("{pad}  labels:\n"));
+            for (key, value) in &self.options.labels {
+                metadata.push_str(&format!(
+                    "{pad}    {}: {}\n",
+                    yaml_string(key),
+                    yaml_string(value)
+                ));
+            }
+        }
+        metadata
+    }
+}
+
+/// Append a YAML mapping after a `key:` that is already written
+fn push_mapping(content: &mut String, entries: &[(&str, String)], indent: usize) {
+    if entries.is_empty() {
+        content.push_str(" {}\n");
+        return;
+    }
+    content.push('\n');
+    for (key, value) in entries {
+        content.push_str(&format!(
+            "{}{}: {}\n",
+            " ".repeat(indent),
+            yaml_string(key),
+            yaml_string(value)
+        ));
+    }
+}
+
+/// Secret names must be DNS subdomains: lowercase alphanumerics, `-` and `.`
+fn ensure_kubernetes_name(name: &str) -> Result<(), String> {
+    let valid = !name.is_empty()
+        && name.len() <= 253
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '.'))
+        && name.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && name.ends_with(|c: char| c.is_ascii_alphanumeric());
+    if !valid {
+        return Err(format!(
+            "\"{}\" is not a valid Kubernetes name (lowercase letters, digits, '-' and '.')",
+            name
+        ));
+    }
+    Ok(())
+}
+
+/// Namespaces must be DNS labels: lowercase alphanumerics and `-`, no dots
+fn ensure_kubernetes_namespace(namespace: &str) -> Result<(), String> {
+    let valid = !namespace.is_empty()
+        && namespace.len() <= 63
+        && namespace
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && namespace.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && namespace.ends_with(|c: char| c.is_ascii_alphanumeric());
+    if !valid {
+        return Err(format!(
+            "\"{}\" is not a valid Kubernetes namespace (lowercase letters, digits and '-')",
+            namespace
+        ));
+    }
+    Ok(())
+}
+
+/// Shells and systemd only accept `[A-Za-z_][A-Za-z0-9_]*` variable names
+fn ensure_shell_name(name: &str) -> Result<(), String> {
+    let valid = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err(format!("{} is not a valid shell variable name", name));
+    }
+    Ok(())
+}
+
+fn json_string(value: &str) -> String {
+    serde_json::Value::from(value).to_string()
+}
+
+/// YAML double-quoted string; like JSON, except that DEL, C1 controls,
+/// the line and paragraph separators (YAML 1.1 reads NEL, LS and PS as line
+/// breaks), byte order marks and non-characters must be escaped too
+fn yaml_string(value: &str) -> String {
+    escape_extra(&json_string(value), |c| {
+        matches!(
+            c,
+            '\u{7f}'..='\u{9f}' | '\u{2028}' | '\u{2029}' | '\u{feff}' | '\u{fffe}' | '\u{ffff}'
+        )
+    })
+}
+
+/// TOML basic string; like JSON, except that DEL must be escaped too
+fn toml_string(value: &str) -> String {
+    escape_extra(&json_string(value), |c| c == '\u{7f}')
+}
+
+/// Replace characters JSON leaves raw with `\uXXXX` escapes
+fn escape_extra(json: &str, needs_escape: impl Fn(char) -> bool) -> String {
+    json.chars()
+        .map(|c| {
+            if needs_escape(c) {
+                format!("\\u{:04X}", c as u32)
+            } else {
+                c.to_string()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn render(format: OutputFormat, entries: &[(String, String)]) -> Result<String, String> {
+        format.formatter(None)?.render(entries)
+    }
+
+    fn one(name: &str, value: &str) -> Vec<(String, String)> {
+        vec![(name.to_string(), value.to_string())]
+    }
+
+    /// Reverse of the systemd escaping: `\` before `\ " $` or a backtick
+    fn parse_systemd(content: &str) -> Vec<(String, String)> {
+        content
+            .lines()
+            .map(|line| {
+                let (name, quoted) = line.split_once('=').unwrap();
+                let inner = quoted.strip_prefix('"').unwrap().strip_suffix('"').unwrap();
+                let mut value = String::new();
+                let mut chars = inner.chars();
+                while let Some(c) = chars.next() {
+                    if c == '\\' {
+                        let next = chars.next().unwrap();
+                        assert!(matches!(next, '\\' | '"' | '$' | '`'));
+                        value.push(next);
+                    } else {
+                        assert!(!matches!(c, '"' | '$' | '`'));
+                        value.push(c);
+                    }
+                }
+                (name.to_string(), value)
+            })
+            .collect()
+    }
+
+    /// Docker takes everything after the first `=` literally
+    fn parse_docker(content: &str) -> Vec<(String, String)> {
+        content
+            .split_terminator('\n')
+            .map(|line| {
+                let (name, value) = line.split_once('=').unwrap();
+                (name.to_string(), value.to_string())
+            })
+            .collect()
+    }
+
+    /// Run a shell script and print `$name` back
+    #[cfg(unix)]
+    fn shell_value(script: &str, name: &str) -> String {
+        let output = std::process::Command::new("sh")
+            .arg("-c")
+            .arg(format!("{}\nprintf '%s' \"${}\"", script, name))
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    fn shell_name() -> impl Strategy<Value = String> {
+        "[A-Za-z_][A-Za-z0-9_]{0,15}"
+    }
+
+    #[test]
+    fn escapes_shell_values() {
+        assert_eq!(
+            render(OutputFormat::Shell, &one("KEY", "it's $HOME")).unwrap(),
+            "#!/bin/sh\nexport KEY='it'\\''s $HOME'\n"
+        );
+        assert!(render(OutputFormat::Shell, &one("1KEY", "x")).is_err());
+        assert!(render(OutputFormat::Direnv, &one("MY-KEY", "x")).is_err());
+    }
+
+    #[test]
+    fn escapes_yaml_values() {
+        assert_eq!(
+            render(OutputFormat::Yaml, &one("ON", "a\"b\\c\nd\u{85}")).unwrap(),
+            "\"ON\": \"a\\\"b\\\\c\\nd\\u0085\"\n"
+        );
+        assert_eq!(
+            render(OutputFormat::Yaml, &one("\u{2028}", "\u{2029}\u{feff}")).unwrap(),
+            "\"\\u2028\": \"\\u2029\\uFEFF\"\n"
+        );
+        assert_eq!(render(OutputFormat::Yaml, &[]).unwrap(), "{}\n");
+    }
+
+    #[test]
+    fn escapes_toml_keys_and_values() {
+        assert_eq!(
+            render(OutputFormat::Toml, &one("plain_KEY-1", "a\"b\u{7f}")).unwrap(),
+            "plain_KEY-1 = \"a\\\"b\\u007F\"\n"
+        );
+        assert_eq!(
+            render(OutputFormat::Toml, &one("dotted.key", "")).unwrap(),
+            "\"dotted.key\" = \"\"\n"
+        );
+        assert_eq!(
+            render(OutputFormat::Toml, &one("", "x")).unwrap(),
+            "\"\" = \"x\"\n"
+        );
+    }
+
+    #[test]
+    fn escapes_systemd_values() {
+        assert_eq!(
+            render(OutputFormat::Systemd, &one("KEY", "a\"b\\c$d`e")).unwrap(),
+            "KEY=\"a\\\"b\\\\c\\$d\\`e\"\n"
+        );
+        assert!(render(OutputFormat::Systemd, &one("KEY", "a\nb")).is_err());
+        assert!(render(OutputFormat::Systemd, &one("KEY", "a\rb")).is_err());
+        assert!(render(OutputFormat::Systemd, &one("MY-KEY", "x")).is_err());
+    }
+
+    #[test]
+    fn writes_docker_values_literally() {
+        assert_eq!(
+            render(OutputFormat::Docker, &one("KEY", "'quoted' \"too\" =$x")).unwrap(),
+            "KEY='quoted' \"too\" =$x\n"
+        );
+        assert!(render(OutputFormat::Docker, &one("KEY", "a\nb")).is_err());
+        assert!(render(OutputFormat::Docker, &one("KEY", "a\rb")).is_err());
+        for name in ["", "A=B", "MY KEY", "#KEY", "KEY\n"] {
+            assert!(
+                render(OutputFormat::Docker, &one(name, "x")).is_err(),
+                "{:?}",
+                name
+            );
+        }
+    }
+
+    fn kubernetes(name: &str, namespace: Option<&str>) -> KubernetesOptions {
+        KubernetesOptions {
+            name: name.to_string(),
+            namespace: namespace.map(str::to_string),
+            labels: BTreeMap::from([("app".to_string(), "web".to_string())]),
+        }
+    }
+
+    fn manifest(format: OutputFormat, entries: &[(String, String)]) -> serde_yaml::Value {
+        let options = kubernetes("app-secrets", Some("prod"));
+        let content = format
+            .formatter(Some(&options))
+            .unwrap()
+            .render(entries)
+            .unwrap();
+        serde_yaml::from_str(&content).unwrap()
+    }
+
+    #[test]
+    fn encodes_kubernetes_data_as_base64() {
+        let entries = vec![
+            ("API_KEY".to_string(), "sk-123".to_string()),
+            ("tls.crt".to_string(), "line 1\nline 2: \"x\"".to_string()),
+        ];
+        let manifest = manifest(OutputFormat::Kubernetes, &entries);
+
+        assert_eq!(manifest["apiVersion"], "v1");
+        assert_eq!(manifest["kind"], "Secret");
+        assert_eq!(manifest["type"], "Opaque");
+        assert_eq!(manifest["metadata"]["name"], "app-secrets");
+        assert_eq!(manifest["metadata"]["namespace"], "prod");
+        assert_eq!(manifest["metadata"]["labels"]["app"], "web");
+        assert_eq!(manifest["data"]["API_KEY"], "c2stMTIz");
+        for (name, value) in &entries {
+            let encoded = manifest["data"][name.as_str()].as_str().unwrap();
+            assert_eq!(BASE64.decode(encoded).unwrap(), value.as_bytes());
+        }
+    }
+
+    #[test]
+    fn writes_placeholders_in_sealed_secrets() {
+        let entries = one("API_KEY", "sk-123");
+        let content = OutputFormat::SealedSecret
+            .formatter(Some(&kubernetes("app-secrets", None)))
+            .unwrap()
+            .render(&entries)
+            .unwrap();
+        assert!(!content.contains("sk-123"));
+        assert!(!content.contains(&BASE64.encode("sk-123")));
+
+        let manifest = manifest(OutputFormat::SealedSecret, &entries);
+        assert_eq!(manifest["kind"], "SealedSecret");
+        assert_eq!(manifest["metadata"]["name"], "app-secrets");
+        assert_eq!(
+            manifest["spec"]["encryptedData"]["API_KEY"],
+            SEALED_PLACEHOLDER
+        );
+        assert_eq!(
+            manifest["spec"]["template"]["metadata"]["namespace"],
+            "prod"
+        );
+        assert_eq!(manifest["spec"]["template"]["type"], "Opaque");
+    }
+
+    #[test]
+    fn writes_empty_kubernetes_data() {
+        let manifest = manifest(OutputFormat::Kubernetes, &[]);
+        assert!(manifest["data"].as_mapping().unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_kubernetes_names() {
+        assert!(OutputFormat::Kubernetes.formatter(None).is_err());
+        for name in [
+            "",
+            "App",
+            "my_secret",
+            "-app",
+            "app.",
+            "app secret",
+            &"a".repeat(254),
+        ] {
+            let options = kubernetes(name, None);
+            assert!(
+                OutputFormat::Kubernetes.formatter(Some(&options)).is_err(),
+                "{:?}",
+                name
+            );
+        }
+        for name in ["app", "app-secrets", "app.example.com", "1app"] {
+            let options = kubernetes(name, None);
+            assert!(OutputFormat::SealedSecret.formatter(Some(&options)).is_ok());
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_kubernetes_namespaces() {
+        for namespace in [
+            "",
+            "Prod",
+            "prod.eu",
+            "prod_eu",
+            "-prod",
+            "prod-",
+            &"a".repeat(64),
+        ] {
+            let options = kubernetes("app", Some(namespace));
+            assert!(
+                OutputFormat::Kubernetes.formatter(Some(&options)).is_err(),
+                "{:?}",
+                namespace
+            );
+        }
+        let options = kubernetes("app", Some("prod-eu-1"));
+        assert!(OutputFormat::Kubernetes.formatter(Some(&options)).is_ok());
+    }
+
+    #[test]
+    fn rejects_invalid_kubernetes_keys() {
+        let formatter = OutputFormat::Kubernetes
+            .formatter(Some(&kubernetes("app", None)))
+            .unwrap();
+        for name in ["", "MY KEY", "a/b", "key:"] {
+            assert!(formatter.render(&one(name, "x")).is_err(), "{:?}", name);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn json_round_trips(entries in proptest::collection::btree_map(any::<String>(), any::<String>(), 0..8)) {
+            let entries: Vec<_> = entries.into_iter().collect();
+            let content = render(OutputFormat::Json, &entries).unwrap();
+            let parsed: BTreeMap<String, String> = serde_json::from_str(&content).unwrap();
+            prop_assert_eq!(parsed.into_iter().collect::<Vec<_>>(), entries);
+        }
+
+        #[test]
+        fn yaml_round_trips(entries in proptest::collection::btree_map(any::<String>(), any::<String>(), 0..8)) {
+            let entries: Vec<_> = entries.into_iter().collect();
+            let content = render(OutputFormat::Yaml, &entries).unwrap();
+            let parsed: BTreeMap<String, String> = serde_yaml::from_str(&content).unwrap();
+            prop_assert_eq!(parsed.into_iter().collect::<Vec<_>>(), entries);
+        }
+
+        #[test]
+        fn toml_round_trips(entries in proptest::collection::btree_map(any::<String>(), any::<String>(), 0..8)) {
+            let entries: Vec<_> = entries.into_iter().collect();
+            let content = render(OutputFormat::Toml, &entries).unwrap();
+            let parsed: BTreeMap<String, String> = toml::from_str(&content).unwrap();
+            prop_assert_eq!(parsed.into_iter().collect::<Vec<_>>(), entries);
+        }
+
+        #[test]
+        fn systemd_round_trips(
+            entries in proptest::collection::btree_map(shell_name(), "[^\r\n]*", 0..8),
+        ) {
+            let entries: Vec<_> = entries.into_iter().collect();
+            let content = render(OutputFormat::Systemd, &entries).unwrap();
+            prop_assert_eq!(parse_systemd(&content), entries);
+        }
+
+        #[test]
+        fn docker_round_trips(
+            entries in proptest::collection::btree_map("[^=#\\s][^=\\s]{0,15}", "[^\r\n]*", 0..8),
+        ) {
+            let entries: Vec<_> = entries.into_iter().collect();
+            let content = render(OutputFormat::Docker, &entries).unwrap();
+            prop_assert_eq!(parse_docker(&content), entries);
+        }
+    }
+
+    #[cfg(unix)]
+    proptest! {
+        // Each case starts a shell, so run fewer of them
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn shell_round_trips(name in shell_name(), value in "[^\0]*") {
+            let content = render(OutputFormat::Shell, &one(&name, &value)).unwrap();
+            prop_assert_eq!(shell_value(&content, &name), value);
+        }
+    }
+}
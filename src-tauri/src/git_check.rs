@@ -0,0 +1,763 @@
+use crate::path_policy::glob_match;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// What `write_env_file` does when the target is inside a git working tree
+/// and not ignored
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GitPolicy {
+    /// Refuse to write
+    #[default]
+    Refuse,
+    /// Write anyway and report it
+    Warn,
+    /// Add the file to the repository's root `.gitignore`, then write
+    AddToGitignore,
+}
+
+/// Outcome of the git check, reported by `write_env_file`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GitCheck {
+    /// Not inside a git working tree
+    #[default]
+    NotInRepository,
+    /// Inside a working tree, and ignored
+    Ignored,
+    /// Inside a working tree and NOT ignored; written anyway
+    NotIgnored,
+    /// Tracked by git, so `.gitignore` does not apply; written anyway
+    Tracked,
+    /// Added to `.gitignore` before writing
+    AddedToGitignore,
+}
+
+/// Check `path` (already resolved) against the git working tree it is in.
+/// With `dry_run`, report what would happen without touching `.gitignore`,
+/// and report a file the policy would refuse instead of failing.
+pub fn check(path: &Path, policy: GitPolicy, dry_run: bool) -> Result<GitCheck, String> {
+    let Some(root) = find_work_tree(path) else {
+        return Ok(GitCheck::NotInRepository);
+    };
+    // Git keeps committing changes to a tracked file whatever .gitignore says
+    let tracked = is_tracked(&root, path);
+    if !tracked && is_ignored(&root, path) {
+        return Ok(GitCheck::Ignored);
+    }
+    let exposed = if tracked {
+        GitCheck::Tracked
+    } else {
+        GitCheck::NotIgnored
+    };
+
+    match policy {
+        GitPolicy::Warn => Ok(exposed),
+        GitPolicy::AddToGitignore if !tracked => {
+            if !dry_run {
+                add_to_gitignore(&root, path)?;
+                if !is_ignored(&root, path) {
+                    return Err(format!(
+                        "Added {} to {}, but it is still not ignored. Check the file's \
+                         .gitignore rules, or use git: \"warn\".",
+                        path.display(),
+                        root.join(".gitignore").display()
+                    ));
+                }
+            }
+            Ok(GitCheck::AddedToGitignore)
+        }
+        _ if dry_run => Ok(exposed),
+        GitPolicy::Refuse | GitPolicy::AddToGitignore if tracked => Err(format!(
+            "{} is tracked by the git repository {}, so .gitignore does not protect it. \
+             Stop tracking it with git rm --cached, or use git: \"warn\".",
+            path.display(),
+            root.display()
+        )),
+        GitPolicy::Refuse | GitPolicy::AddToGitignore => Err(format!(
+            "{} is inside the git repository {} and not covered by .gitignore. \
+             Add it to .gitignore, or use git: \"add_to_gitignore\" or \"warn\".",
+            path.display(),
+            root.display()
+        )),
+    }
+}
+
+/// Walk up from the file's directory to the nearest `.git` (a directory, or
+/// a file in worktrees and submodules)
+fn find_work_tree(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .skip(1)
+        .find(|dir| dir.join(".git").exists())
+        .map(Path::to_path_buf)
+}
+
+/// The repository's git directory: `.git`, or where a `.git` file points in
+/// worktrees and submodules
+fn git_dir(root: &Path) -> Option<PathBuf> {
+    let dot_git = root.join(".git");
+    if dot_git.is_dir() {
+        return Some(dot_git);
+    }
+    let content = std::fs::read_to_string(&dot_git).ok()?;
+    let dir = content
+        .lines()
+        .find_map(|line| line.strip_prefix("gitdir:"))?
+        .trim();
+    Some(root.join(dir))
+}
+
+/// Where a git directory's shared files, like `info/exclude`, live: the main
+/// repository's for a linked worktree, otherwise the git directory itself
+fn common_dir(git_dir: &Path) -> PathBuf {
+    match std::fs::read_to_string(git_dir.join("commondir")) {
+        Ok(content) => git_dir.join(content.trim()),
+        Err(_) => git_dir.to_path_buf(),
+    }
+}
+
+/// Whether the repository's index has an entry for `path`
+fn is_tracked(root: &Path, path: &Path) -> bool {
+    let Ok(relative) = path.strip_prefix(root) else {
+        return false;
+    };
+    let relative: Vec<_> = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect();
+    let relative = relative.join("/");
+
+    let Some(git_dir) = git_dir(root) else {
+        return false;
+    };
+    let Ok(index) = std::fs::read(git_dir.join("index")) else {
+        return false;
+    };
+    index_paths(&index, object_id_len(&git_dir))
+        .is_some_and(|paths| paths.iter().any(|p| *p == relative.as_bytes()))
+}
+
+/// Bytes in an object ID: SHA-1, or SHA-256 in repositories that opted in
+fn object_id_len(git_dir: &Path) -> usize {
+    let config = std::fs::read_to_string(git_dir.join("config")).unwrap_or_default();
+    let sha256 = config.lines().any(|line| {
+        let line: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+        line.eq_ignore_ascii_case("objectformat=sha256")
+    });
+    if sha256 {
+        32
+    } else {
+        20
+    }
+}
+
+/// Paths of the entries in a git index file (versions 2 to 4), or `None` if
+/// it cannot be read
+fn index_paths(index: &[u8], id_len: usize) -> Option<Vec<Vec<u8>>> {
+    let be32 = |at: usize| -> Option<u32> {
+        Some(u32::from_be_bytes(index.get(at..at + 4)?.try_into().ok()?))
+    };
+    if index.get(..4)? != b"DIRC" {
+        return None;
+    }
+    let version = be32(4)?;
+    if !(2..=4).contains(&version) {
+        return None;
+    }
+    let count = be32(8)? as usize;
+
+    let mut paths: Vec<Vec<u8>> = Vec::new();
+    let mut pos = 12;
+    for _ in 0..count {
+        let start = pos;
+        // Ten 32-bit stat fields, the object ID, then 16 bits of flags
+        let flags_at = start + 40 + id_len;
+        let flags = u16::from_be_bytes(index.get(flags_at..flags_at + 2)?.try_into().ok()?);
+        pos = flags_at + 2;
+        if version >= 3 && flags & 0x4000 != 0 {
+            pos += 2;
+        }
+
+        let path = if version >= 4 {
+            // Each path drops the given number of bytes from the end of the
+            // previous one and appends the rest
+            let (strip, len) = read_offset_varint(index.get(pos..)?)?;
+            pos += len;
+            let end = pos + index.get(pos..)?.iter().position(|&b| b == 0)?;
+            let previous = paths.last().map(Vec::as_slice).unwrap_or_default();
+            let mut path = previous[..previous.len().checked_sub(strip)?].to_vec();
+            path.extend_from_slice(&index[pos..end]);
+            pos = end + 1;
+            path
+        } else {
+            let end = pos + index.get(pos..)?.iter().position(|&b| b == 0)?;
+            let path = index[pos..end].to_vec();
+            // Entries are padded with one to eight NULs to a multiple of 8
+            pos = start + ((end - start + 8) & !7);
+            path
+        };
+        paths.push(path);
+    }
+    Some(paths)
+}
+
+/// Git's variable-length integer for index paths: returns the value and the
+/// bytes it took
+fn read_offset_varint(bytes: &[u8]) -> Option<(usize, usize)> {
+    let mut value = usize::from(*bytes.first()? & 0x7f);
+    let mut len = 1;
+    while bytes[len - 1] & 0x80 != 0 {
+        let byte = *bytes.get(len)?;
+        value = ((value + 1) << 7) | usize::from(byte & 0x7f);
+        len += 1;
+    }
+    Some((value, len))
+}
+
+/// One `.gitignore` line
+struct Rule {
+    /// Directory the pattern is relative to
+    base: PathBuf,
+    pattern: String,
+    negated: bool,
+    dir_only: bool,
+    /// Matched against the path from `base`, not just the file name
+    anchored: bool,
+}
+
+/// Evaluate the global excludes file, `.git/info/exclude` and every
+/// `.gitignore` from the root down to the file's directory, in git's order
+fn is_ignored(root: &Path, path: &Path) -> bool {
+    let mut rules = Vec::new();
+    if let Some(global) = global_excludes_file() {
+        read_rules(&mut rules, root, &global);
+    }
+    if let Some(git_dir) = git_dir(root) {
+        read_rules(
+            &mut rules,
+            root,
+            &common_dir(&git_dir).join("info").join("exclude"),
+        );
+    }
+
+    // Directories between the root and the file, top down
+    let mut dirs: Vec<&Path> = path
+        .ancestors()
+        .skip(1)
+        .take_while(|dir| dir.starts_with(root))
+        .collect();
+    dirs.reverse();
+
+    for (i, dir) in dirs.iter().enumerate() {
+        // A file inside an excluded directory cannot be re-included
+        if i > 0 && is_excluded(&rules, dir, true) {
+            return true;
+        }
+        read_rules(&mut rules, dir, &dir.join(".gitignore"));
+    }
+    is_excluded(&rules, path, false)
+}
+
+fn global_excludes_file() -> Option<PathBuf> {
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".config")))?;
+    Some(config.join("git").join("ignore"))
+}
+
+fn read_rules(rules: &mut Vec<Rule>, base: &Path, file: &Path) {
+    let Ok(content) = std::fs::read_to_string(file) else {
+        return;
+    };
+
+    for line in content.lines() {
+        let line = trim_unescaped_spaces(line.trim_end_matches('\r'));
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (negated, line) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let (dir_only, line) = match line.strip_suffix('/') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        if line.is_empty() {
+            continue;
+        }
+
+        rules.push(Rule {
+            base: base.to_path_buf(),
+            pattern: unescape_pattern(line.trim_start_matches('/')),
+            negated,
+            dir_only,
+            anchored: line.contains('/'),
+        });
+    }
+}
+
+/// Drop trailing spaces, except one escaped with a backslash
+fn trim_unescaped_spaces(line: &str) -> &str {
+    let mut end = line.len();
+    while line[..end].ends_with(' ') && !line[..end - 1].ends_with('\\') {
+        end -= 1;
+    }
+    &line[..end]
+}
+
+/// Turn a `.gitignore` pattern into `glob_match` syntax: a backslash makes
+/// the next character literal, which for `*`, `?` and `[` means a class
+/// holding only that character
+fn unescape_pattern(pattern: &str) -> String {
+    let mut glob = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(c @ ('*' | '?' | '[')) => {
+                    glob.push('[');
+                    glob.push(c);
+                    glob.push(']');
+                }
+                Some(c) => glob.push(c),
+                None => {}
+            },
+            c => glob.push(c),
+        }
+    }
+    glob
+}
+
+/// Escape a path for `.gitignore` so it matches only itself
+fn escape_pattern(relative: &str) -> String {
+    let kept = relative.trim_end_matches(' ');
+    let mut pattern = String::with_capacity(relative.len() + 2);
+    for c in kept.chars() {
+        if matches!(c, '\\' | '*' | '?' | '[' | '!' | '#') {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    // Trailing spaces are dropped unless escaped
+    for _ in kept.len()..relative.len() {
+        pattern.push_str("\\ ");
+    }
+    pattern
+}
+
+/// Whether the last rule matching `candidate` excludes it
+fn is_excluded(rules: &[Rule], candidate: &Path, is_dir: bool) -> bool {
+    rules
+        .iter()
+        .rev()
+        .find(|rule| rule_matches(rule, candidate, is_dir))
+        .is_some_and(|rule| !rule.negated)
+}
+
+fn rule_matches(rule: &Rule, candidate: &Path, is_dir: bool) -> bool {
+    if rule.dir_only && !is_dir {
+        return false;
+    }
+    let Ok(relative) = candidate.strip_prefix(&rule.base) else {
+        return false;
+    };
+    if relative.as_os_str().is_empty() {
+        return false;
+    }
+
+    if rule.anchored {
+        let relative: Vec<_> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect();
+        glob_match(&rule.pattern, &relative.join("/"))
+    } else {
+        let name = candidate.file_name().unwrap_or_default().to_string_lossy();
+        glob_match(&rule.pattern, &name)
+    }
+}
+
+/// Append `/relative/path`, escaped, to the root `.gitignore`, creating it
+/// if needed
+fn add_to_gitignore(root: &Path, path: &Path) -> Result<(), String> {
+    let relative = path
+        .strip_prefix(root)
+        .map_err(|_| format!("{} is outside {}", path.display(), root.display()))?;
+    let entry: Vec<_> = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect();
+    let entry = entry.join("/");
+    if entry.contains(['\n', '\r']) {
+        return Err(format!(
+            "{} cannot be added to .gitignore, as its name has a line break",
+            path.display()
+        ));
+    }
+
+    let gitignore = root.join(".gitignore");
+    let existing = std::fs::read_to_string(&gitignore).unwrap_or_default();
+    let separator = if existing.is_empty() || existing.ends_with('\n') {
+        ""
+    } else {
+        "\n"
+    };
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&gitignore)
+        .map_err(|e| e.to_string())?;
+    file.write_all(format!("{}/{}\n", separator, escape_pattern(&entry)).as_bytes())
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::{tempdir, TempDir};
+
+    /// A temporary git working tree with the given root `.gitignore`
+    fn repo(gitignore: &str) -> TempDir {
+        let dir = tempdir().unwrap();
+        fs::create_dir_all(dir.path().join(".git").join("info")).unwrap();
+        fs::write(dir.path().join(".gitignore"), gitignore).unwrap();
+        dir
+    }
+
+    /// Write an index listing `paths` in the given format version
+    fn write_index(git_dir: &Path, version: u32, paths: &[&str]) {
+        let mut index = b"DIRC".to_vec();
+        index.extend_from_slice(&version.to_be_bytes());
+        index.extend_from_slice(&(paths.len() as u32).to_be_bytes());
+
+        let mut previous: &str = "";
+        for path in paths {
+            let start = index.len();
+            index.extend_from_slice(&[0; 60]);
+            // Version 3 entries may carry a second flags word
+            let extended = version == 3;
+            let flags = path.len().min(0xfff) as u16 | if extended { 0x4000 } else { 0 };
+            index.extend_from_slice(&flags.to_be_bytes());
+            if extended {
+                index.extend_from_slice(&[0, 0]);
+            }
+
+            if version == 4 {
+                let common = previous
+                    .bytes()
+                    .zip(path.bytes())
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                index.push((previous.len() - common) as u8);
+                index.extend_from_slice(&path.as_bytes()[common..]);
+                index.push(0);
+            } else {
+                index.extend_from_slice(path.as_bytes());
+                let len = index.len() - start;
+                index.resize(start + ((len + 8) & !7), 0);
+            }
+            previous = path;
+        }
+        fs::write(git_dir.join("index"), index).unwrap();
+    }
+
+    fn check_file(root: &Path, relative: &str, policy: GitPolicy) -> Result<GitCheck, String> {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        check(&path, policy, false)
+    }
+
+    fn ignored(root: &Path, relative: &str) -> bool {
+        check_file(root, relative, GitPolicy::Warn).unwrap() == GitCheck::Ignored
+    }
+
+    #[test]
+    fn outside_a_repository() {
+        let temp = tempdir().unwrap();
+        let dir = temp.path();
+
+        assert_eq!(
+            check(&dir.join(".env"), GitPolicy::Refuse, false),
+            Ok(GitCheck::NotInRepository)
+        );
+    }
+
+    #[test]
+    fn unanchored_patterns_match_names_at_any_depth() {
+        let repo = repo(".env\n*.local\n");
+        let root = repo.path();
+
+        assert!(ignored(root, ".env"));
+        assert!(ignored(root, "app/web/.env"));
+        assert!(ignored(root, "app/.env.local"));
+        assert!(!ignored(root, "app/.env.example"));
+    }
+
+    #[test]
+    fn anchored_patterns_match_from_their_directory() {
+        let repo = repo("/.env\nconfig/*.env\n");
+        let root = repo.path();
+
+        assert!(ignored(root, ".env"));
+        assert!(!ignored(root, "app/.env"));
+        assert!(ignored(root, "config/prod.env"));
+        assert!(!ignored(root, "app/config/prod.env"));
+        assert!(!ignored(root, "config/nested/prod.env"));
+
+        // A nested .gitignore is relative to its own directory
+        fs::create_dir_all(root.join("app")).unwrap();
+        fs::write(root.join("app").join(".gitignore"), "/local.env\n").unwrap();
+        assert!(ignored(root, "app/local.env"));
+        assert!(!ignored(root, "local.env"));
+        assert!(!ignored(root, "app/sub/local.env"));
+    }
+
+    #[test]
+    fn last_matching_rule_wins() {
+        let repo = repo("*.env\n!example.env\n");
+        let root = repo.path();
+        fs::create_dir_all(root.join("app")).unwrap();
+        fs::write(root.join("app").join(".gitignore"), "example.env\n").unwrap();
+
+        assert!(ignored(root, "prod.env"));
+        assert!(!ignored(root, "example.env"));
+        // The deeper .gitignore comes later, so it wins
+        assert!(ignored(root, "app/example.env"));
+    }
+
+    #[test]
+    fn directory_rules_only_match_directories() {
+        let repo = repo("secrets/\n");
+        let root = repo.path();
+
+        assert!(ignored(root, "secrets/.env"));
+        assert!(ignored(root, "app/secrets/.env"));
+        // A file with the directory's name is not matched
+        assert!(!ignored(root, "app/secrets"));
+    }
+
+    #[test]
+    fn files_in_excluded_directories_cannot_be_reincluded() {
+        let repo = repo("build\n!build/.env\n");
+        let root = repo.path();
+
+        assert!(ignored(root, "build/.env"));
+        assert!(ignored(root, "build/nested/.env"));
+
+        // Excluding the parent in .git/info/exclude works the same way
+        fs::write(root.join(".git").join("info").join("exclude"), "vendor/\n").unwrap();
+        fs::create_dir_all(root.join("vendor")).unwrap();
+        fs::write(root.join("vendor").join(".gitignore"), "!.env\n").unwrap();
+        assert!(ignored(root, "vendor/.env"));
+    }
+
+    #[test]
+    fn refuses_unignored_files_except_in_a_dry_run() {
+        let repo = repo("");
+        let root = repo.path();
+        let path = root.join(".env");
+
+        let err = check(&path, GitPolicy::Refuse, false).unwrap_err();
+        assert!(err.contains("not covered by .gitignore"), "{}", err);
+        assert_eq!(
+            check(&path, GitPolicy::Refuse, true),
+            Ok(GitCheck::NotIgnored)
+        );
+        assert_eq!(
+            check(&path, GitPolicy::Warn, false),
+            Ok(GitCheck::NotIgnored)
+        );
+    }
+
+    #[test]
+    fn adds_to_gitignore_unless_dry_run() {
+        let repo = repo("node_modules");
+        let root = repo.path();
+        let path = root.join("app").join(".env");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+        assert_eq!(
+            check(&path, GitPolicy::AddToGitignore, true),
+            Ok(GitCheck::AddedToGitignore)
+        );
+        assert_eq!(
+            fs::read_to_string(root.join(".gitignore")).unwrap(),
+            "node_modules"
+        );
+
+        assert_eq!(
+            check(&path, GitPolicy::AddToGitignore, false),
+            Ok(GitCheck::AddedToGitignore)
+        );
+        assert_eq!(
+            fs::read_to_string(root.join(".gitignore")).unwrap(),
+            "node_modules\n/app/.env\n"
+        );
+        assert_eq!(
+            check(&path, GitPolicy::Refuse, false),
+            Ok(GitCheck::Ignored)
+        );
+    }
+
+    #[test]
+    fn adds_special_names_literally() {
+        let repo = repo("");
+        let root = repo.path();
+        let names = [
+            "*.env",
+            "[ab].env",
+            "!x.env",
+            "#y.env",
+            "q?.env",
+            "a\\b.env",
+            "space.env  ",
+        ];
+        for name in names {
+            assert_eq!(
+                check_file(root, name, GitPolicy::AddToGitignore),
+                Ok(GitCheck::AddedToGitignore),
+                "{}",
+                name
+            );
+            assert!(ignored(root, name), "{}", name);
+        }
+        assert_eq!(
+            fs::read_to_string(root.join(".gitignore")).unwrap(),
+            "/\\*.env\n/\\[ab].env\n/\\!x.env\n/\\#y.env\n/q\\?.env\n/a\\\\b.env\n/space.env\\ \\ \n"
+        );
+
+        // The patterns match nothing else
+        for other in ["x.env", "a.env", "qz.env", "space.env", "space.env "] {
+            assert!(!ignored(root, other), "{}", other);
+        }
+    }
+
+    #[test]
+    fn refuses_names_gitignore_cannot_hold() {
+        let repo = repo("");
+        let root = repo.path();
+
+        let err = check_file(root, "a\nb.env", GitPolicy::AddToGitignore).unwrap_err();
+        assert!(err.contains("line break"), "{}", err);
+        assert_eq!(fs::read_to_string(root.join(".gitignore")).unwrap(), "");
+    }
+
+    #[test]
+    fn fails_when_the_added_entry_does_not_apply() {
+        // A later rule re-includes everything under app
+        let repo = repo("");
+        let root = repo.path();
+        fs::create_dir_all(root.join("app")).unwrap();
+        fs::write(root.join("app").join(".gitignore"), "!*\n").unwrap();
+
+        let err = check_file(root, "app/.env", GitPolicy::AddToGitignore).unwrap_err();
+        assert!(err.contains("still not ignored"), "{}", err);
+        assert!(!ignored(root, "app/.env"));
+    }
+
+    #[test]
+    fn tracked_files_are_not_protected_by_gitignore() {
+        let repo = repo(".env\n");
+        let root = repo.path();
+        write_index(
+            &root.join(".git"),
+            2,
+            &[".gitignore", "app/.env", "app/main.rs"],
+        );
+
+        assert!(ignored(root, ".env"));
+        assert_eq!(
+            check_file(root, "app/.env", GitPolicy::Warn),
+            Ok(GitCheck::Tracked)
+        );
+        let err = check_file(root, "app/.env", GitPolicy::Refuse).unwrap_err();
+        assert!(err.contains("is tracked"), "{}", err);
+        assert!(check_file(root, "app/.env", GitPolicy::AddToGitignore).is_err());
+        assert_eq!(
+            check(&root.join("app").join(".env"), GitPolicy::Refuse, true),
+            Ok(GitCheck::Tracked)
+        );
+    }
+
+    #[test]
+    fn reads_the_exclude_file_of_a_submodule() {
+        let temp = tempdir().unwrap();
+        let root = temp.path();
+        let git_dir = root.join(".git").join("modules").join("sub");
+        let tree = root.join("sub");
+        fs::create_dir_all(git_dir.join("info")).unwrap();
+        fs::create_dir_all(&tree).unwrap();
+        fs::write(tree.join(".git"), "gitdir: ../.git/modules/sub\n").unwrap();
+        fs::write(git_dir.join("info").join("exclude"), ".env\n").unwrap();
+
+        assert_eq!(
+            check(&tree.join(".env"), GitPolicy::Refuse, false),
+            Ok(GitCheck::Ignored)
+        );
+        assert_eq!(
+            check(&tree.join("other.env"), GitPolicy::Warn, false),
+            Ok(GitCheck::NotIgnored)
+        );
+    }
+
+    #[test]
+    fn reads_every_index_version() {
+        let paths = [".env", "app/.env", "app/.env.local", "b", "config/prod.env"];
+        for version in 2..=4 {
+            let repo = repo("");
+            let root = repo.path();
+            write_index(&root.join(".git"), version, &paths);
+
+            let index = fs::read(root.join(".git").join("index")).unwrap();
+            let read = index_paths(&index, 20).unwrap();
+            assert_eq!(
+                read,
+                paths.map(|p| p.as_bytes().to_vec()),
+                "version {}",
+                version
+            );
+            assert!(is_tracked(root, &root.join("app").join(".env.local")));
+            assert!(!is_tracked(root, &root.join("app").join(".env.prod")));
+        }
+
+        assert!(index_paths(b"DIRC\0\0\0\x02\0\0\0\x01", 20).is_none());
+        assert!(index_paths(b"not an index", 20).is_none());
+    }
+
+    #[test]
+    fn follows_gitdir_files() {
+        let temp = tempdir().unwrap();
+        let root = temp.path();
+        let git_dir = root.join("main.git").join("worktrees").join("wt");
+        let tree = root.join("wt");
+        fs::create_dir_all(&git_dir).unwrap();
+        fs::create_dir_all(&tree).unwrap();
+        fs::write(
+            tree.join(".git"),
+            format!("gitdir: {}\n", git_dir.display()),
+        )
+        .unwrap();
+        write_index(&git_dir, 2, &[".env"]);
+
+        assert_eq!(super::git_dir(&tree), Some(git_dir.clone()));
+        assert!(!is_ignored(&tree, &tree.join("local.env")));
+
+        // A linked worktree reads the main repository's exclude file
+        fs::write(git_dir.join("commondir"), "../..\n").unwrap();
+        fs::create_dir_all(root.join("main.git").join("info")).unwrap();
+        fs::write(
+            root.join("main.git").join("info").join("exclude"),
+            "local.env\n",
+        )
+        .unwrap();
+        assert!(is_ignored(&tree, &tree.join("local.env")));
+        assert_eq!(
+            check(&tree.join(".env"), GitPolicy::Warn, false),
+            Ok(GitCheck::Tracked)
+        );
+    }
+}
@@ -1,13 +1,52 @@
+mod approvals;
+mod atomic_file;
+mod autolock;
 mod commands;
+mod crypto;
 mod db;
+mod dotenv;
+mod formats;
+mod git_check;
+mod mcp;
+mod mcp_bridge;
+mod mcp_http;
+mod migrations;
+mod path_policy;
+#[cfg(test)]
+mod test_support;
+mod vault;
+
+use tauri::Emitter;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
-        .setup(|_app| {
+        .setup(|app| {
             // Initialize database
             db::init_db().expect("Failed to initialize database");
+
+            // Lock the vault after inactivity and tell the frontend
+            let handle = app.handle().clone();
+            autolock::spawn(move || {
+                let _ = handle.emit("vault-locked", ());
+            });
+
+            // Ask the user about writes requested by MCP clients
+            let handle = app.handle().clone();
+            approvals::spawn(move |approval| {
+                let _ = handle.emit("write-approval-requested", approval);
+            });
+
+            // Serve MCP for HTTP clients and `secret-mcp-server`, on the
+            // usual port if it is free, unless the user turned that off
+            if db::get_mcp_http_enabled().unwrap_or(db::DEFAULT_MCP_HTTP_ENABLED) {
+                let started =
+                    mcp_http::start(mcp_http::DEFAULT_PORT).or_else(|_| mcp_http::start(0));
+                if let Err(e) = started {
+                    eprintln!("Failed to start the MCP HTTP server: {}", e);
+                }
+            }
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -16,10 +55,65 @@ pub fn run() {
             commands::create_secret,
             commands::update_secret,
             commands::delete_secret,
+            commands::list_trash,
+            commands::restore_secret,
+            commands::purge_trash,
+            commands::list_secret_versions,
+            commands::restore_secret_version,
+            commands::list_projects,
+            commands::create_project,
+            commands::update_project,
+            commands::delete_project,
+            commands::list_environments,
+            commands::create_environment,
+            commands::rename_environment,
+            commands::delete_environment,
+            commands::list_secret_environment_values,
+            commands::set_secret_environment_value,
+            commands::clear_secret_environment_value,
+            commands::list_tags,
             commands::search_secrets,
             commands::write_env,
+            commands::diff_env,
+            commands::fill_env_from_template,
+            commands::list_env_backups,
+            commands::restore_env_backup,
             commands::get_db_path,
+            commands::vault_status,
+            commands::unlock_vault,
+            commands::lock_vault,
+            commands::get_auto_lock_minutes,
+            commands::set_auto_lock_minutes,
+            commands::get_version_retention,
+            commands::set_version_retention,
+            commands::get_trash_retention_days,
+            commands::set_trash_retention_days,
+            commands::get_env_backup_retention,
+            commands::set_env_backup_retention,
+            commands::get_path_policy,
+            commands::set_path_policy,
+            commands::start_mcp_http,
+            commands::stop_mcp_http,
+            commands::get_mcp_http_status,
+            commands::get_mcp_http_enabled,
+            commands::set_mcp_http_enabled,
+            commands::list_write_approvals,
+            commands::approve_write,
+            commands::deny_write,
+            commands::list_approval_rules,
+            commands::delete_approval_rule,
+            commands::query_audit_log,
+            commands::export_audit_log,
+            commands::verify_audit_log,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+/// Serve MCP on stdin/stdout; the entry point of `secret-mcp-server`
+pub fn run_mcp_server() {
+    if let Err(e) = mcp_bridge::serve(std::io::stdin().lock(), std::io::stdout()) {
+        eprintln!("Fatal error: {}", e);
+        std::process::exit(1);
+    }
+}
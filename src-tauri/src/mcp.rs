@@ -0,0 +1,694 @@
+use crate::approvals::{self, WriteRequest};
+use crate::db::{self, WriteEnvOptions, WriteEnvReport};
+use crate::dotenv;
+use crate::formats::OutputFormat;
+use crate::git_check::GitCheck;
+use crate::vault;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// MCP protocol revisions this server speaks, newest first
+const PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2025-03-26", "2024-11-05"];
+
+// JSON-RPC error codes
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// State of one client connection. Tool calls use the vault as the user
+/// left it in the app.
+#[derive(Debug, Clone)]
+pub struct Session {
+    /// Name the client gave when initializing, shown when it asks to write
+    client: String,
+    /// Approvals that tool calls are waiting for, by JSON-RPC request ID, so
+    /// cancelling a call withdraws its approval
+    waiting: Arc<Mutex<HashMap<String, String>>>,
+}
+
+#[derive(Deserialize)]
+struct ToolCall {
+    name: String,
+    #[serde(default)]
+    arguments: Value,
+}
+
+#[derive(Deserialize)]
+struct SearchArgs {
+    query: String,
+    project: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct WriteEnvArgs {
+    keys: Vec<String>,
+    path: String,
+    #[serde(flatten)]
+    options: WriteEnvOptions,
+}
+
+#[derive(Deserialize)]
+struct FillTemplateArgs {
+    template: String,
+    path: String,
+    #[serde(flatten)]
+    options: WriteEnvOptions,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Session {
+            client: "unknown client".to_string(),
+            waiting: Arc::default(),
+        }
+    }
+}
+
+impl Session {
+    /// Handle one JSON-RPC message, returning the response if it is a
+    /// request. Notifications, and responses to requests we never send, get
+    /// none.
+    pub fn handle(&mut self, text: &str) -> Option<Value> {
+        match serde_json::from_str::<Value>(text) {
+            Ok(message) => self.handle_message(&message),
+            Err(e) => Some(error_response(Value::Null, PARSE_ERROR, &e.to_string())),
+        }
+    }
+
+    fn handle_message(&mut self, message: &Value) -> Option<Value> {
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            if message.get("result").is_some() || message.get("error").is_some() {
+                return None;
+            }
+            let id = message.get("id").cloned().unwrap_or(Value::Null);
+            return Some(error_response(id, INVALID_REQUEST, "Invalid request"));
+        };
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+        if method == "notifications/cancelled" {
+            self.cancel(&params);
+        }
+        let id = message.get("id")?.clone();
+
+        let result = match method {
+            "initialize" => Ok(initialize(self, &params)),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": tools() })),
+            "tools/call" => call_tool(self, &id, params),
+            _ => Err((METHOD_NOT_FOUND, format!("Method not found: {}", method))),
+        };
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err((code, message)) => error_response(id, code, &message),
+        })
+    }
+
+    /// Deny the approval the cancelled request is waiting for, if any
+    fn cancel(&self, params: &Value) {
+        let Some(request_id) = params.get("requestId") else {
+            return;
+        };
+        let approval_id = match self.waiting.lock() {
+            Ok(mut waiting) => waiting.remove(&request_id.to_string()),
+            Err(_) => None,
+        };
+        if let Some(approval_id) = approval_id {
+            // Fails only if the user has just decided it
+            let _ = db::decide_write_approval(&approval_id, false, None);
+        }
+    }
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
+
+/// Agree on the client's protocol revision if we speak it, else our newest
+fn initialize(session: &mut Session, params: &Value) -> Value {
+    if let Some(name) = params.pointer("/clientInfo/name").and_then(Value::as_str) {
+        session.client = name.to_string();
+    }
+
+    let requested = params.get("protocolVersion").and_then(Value::as_str);
+    let version = PROTOCOL_VERSIONS
+        .iter()
+        .find(|version| Some(**version) == requested)
+        .unwrap_or(&PROTOCOL_VERSIONS[0]);
+
+    json!({
+        "protocolVersion": version,
+        "capabilities": { "tools": {} },
+        "serverInfo": {
+            "name": "secret-mcp-server",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+    })
+}
+
+/// Run a tool. Tool failures are reported in the result, as MCP expects,
+/// so the AI can read them.
+fn call_tool(session: &Session, id: &Value, params: Value) -> Result<Value, (i64, String)> {
+    let call: ToolCall =
+        serde_json::from_value(params).map_err(|e| (INVALID_PARAMS, e.to_string()))?;
+
+    Ok(match run_tool(session, id, &call.name, call.arguments) {
+        Ok(text) => json!({ "content": [{ "type": "text", "text": text }] }),
+        Err(e) => json!({
+            "content": [{ "type": "text", "text": format!("Error: {}", e) }],
+            "isError": true,
+        }),
+    })
+}
+
+/// Every call ends up in the audit log once: recorded by the `db` function
+/// it reaches, or here if it fails before that
+fn run_tool(session: &Session, id: &Value, name: &str, arguments: Value) -> Result<String, String> {
+    let actor = db::Actor::Mcp(session.client.clone());
+    match name {
+        "search_secrets" => {
+            let args: SearchArgs =
+                audit_failure(&actor, name, &[], None, parse_arguments(arguments))?;
+            let results = db::search_secrets(&args.query, args.project.as_deref());
+
+            // Search reveals names, not values, but which names is worth knowing
+            let names: Vec<String> = results
+                .iter()
+                .flatten()
+                .map(|result| result.name.clone())
+                .collect();
+            db::audit(&actor, name, &names, None, &results);
+            serde_json::to_string_pretty(&results?).map_err(|e| e.to_string())
+        }
+        "write_env" => {
+            let WriteEnvArgs {
+                keys,
+                path,
+                mut options,
+            } = audit_failure(&actor, name, &[], None, parse_arguments(arguments))?;
+            options.dry_run = false;
+            // Checked before asking the user, as the write would fail
+            if let Some(tag) = &options.tag {
+                let tag = audit_failure(&actor, name, &keys, Some(&path), db::normalize_tag(tag))?;
+                options.tag = Some(tag);
+            }
+
+            // The requested secrets, including those written under aliases
+            let mut secrets = keys.clone();
+            for secret in options.aliases.values() {
+                if !secrets.contains(secret) {
+                    secrets.push(secret.clone());
+                }
+            }
+            let approved = ensure_unlocked().and_then(|()| {
+                wait_for_approval(session, id, name, secrets.clone(), &path, None, &options)
+            });
+            audit_failure(&actor, name, &secrets, Some(&path), approved)?;
+            let report = db::write_env_file(&actor, &keys, &path, &options)?;
+
+            let mut message = format!(
+                "Successfully wrote {} secret(s) to {}",
+                report.written, path
+            );
+            if !report.missing.is_empty() {
+                message += &format!(
+                    "\nMissing secrets (not found): {}",
+                    report.missing.join(", ")
+                );
+            }
+            let renamed: Vec<String> = report
+                .mappings
+                .iter()
+                .filter(|m| m.found && m.variable != m.secret)
+                .map(|m| format!("{} -> {}", m.secret, m.variable))
+                .collect();
+            if !renamed.is_empty() {
+                message += &format!("\nWritten as: {}", renamed.join(", "));
+            }
+            message += &git_note(&report, &path);
+            Ok(message)
+        }
+        "fill_env_from_template" => {
+            let FillTemplateArgs {
+                template,
+                path,
+                mut options,
+            } = audit_failure(&actor, name, &[], None, parse_arguments(arguments))?;
+            options.dry_run = false;
+
+            let template_variables = ensure_unlocked().and_then(|()| read_template(&template));
+            let (template_path, variables) =
+                audit_failure(&actor, name, &[], Some(&path), template_variables)?;
+            let approved = wait_for_approval(
+                session,
+                id,
+                name,
+                variables.clone(),
+                &path,
+                Some(&template_path),
+                &options,
+            );
+            audit_failure(&actor, name, &variables, Some(&path), approved)?;
+            let report = db::fill_env_from_template(&actor, &template_path, &path, &options)?;
+
+            let mut message = format!(
+                "Filled {} variable(s) in {} from {}",
+                report.written, path, template
+            );
+            if !report.missing.is_empty() {
+                message += &format!(
+                    "\nNo matching secret (template default kept): {}",
+                    report.missing.join(", ")
+                );
+            }
+            message += &git_note(&report, &path);
+            Ok(message)
+        }
+        "diff_env" => {
+            let WriteEnvArgs {
+                keys,
+                path,
+                mut options,
+            } = audit_failure(&actor, name, &[], None, parse_arguments(arguments))?;
+            options.dry_run = true;
+            audit_failure(&actor, name, &keys, Some(&path), ensure_unlocked())?;
+            let report = db::write_env_file(&actor, &keys, &path, &options)?;
+            serde_json::to_string_pretty(&report.diff.unwrap_or_default())
+                .map_err(|e| e.to_string())
+        }
+        _ => audit_failure(
+            &actor,
+            name,
+            &[],
+            None,
+            Err(format!("Unknown tool: {}", name)),
+        ),
+    }
+}
+
+/// Record `result` in the audit log if it is a failure, for calls that stop
+/// before reaching a `db` function that records its own outcome
+fn audit_failure<T>(
+    actor: &db::Actor,
+    tool: &str,
+    secrets: &[String],
+    path: Option<&str>,
+    result: Result<T, String>,
+) -> Result<T, String> {
+    if result.is_err() {
+        db::audit(actor, tool, secrets, path, &result);
+    }
+    result
+}
+
+/// Ask the user to approve a write of `keys` to `path`, from `template` if
+/// filling one, for the tool call with request ID `id`
+fn wait_for_approval(
+    session: &Session,
+    id: &Value,
+    tool: &str,
+    keys: Vec<String>,
+    path: &str,
+    template: Option<&str>,
+    options: &WriteEnvOptions,
+) -> Result<(), String> {
+    // Show, and match rules against, the file that would really be written
+    let path = db::check_write_path(Path::new(path))?;
+    let request_id = id.to_string();
+    let request = WriteRequest {
+        client: &session.client,
+        tool,
+        keys,
+        tag: options.tag.clone(),
+        project: options.project.clone(),
+        environment: options.environment.clone(),
+        template: template.map(String::from),
+        mode: options.mode,
+        format: options.format.unwrap_or_else(|| OutputFormat::infer(&path)),
+        path: path.to_string_lossy().into_owned(),
+    };
+    let approved = approvals::wait_for_approval(request, |approval_id| {
+        if let Ok(mut waiting) = session.waiting.lock() {
+            waiting.insert(request_id.clone(), approval_id.to_string());
+        }
+    });
+    if let Ok(mut waiting) = session.waiting.lock() {
+        waiting.remove(&request_id);
+    }
+    approved
+}
+
+/// Resolve `template` against the path policy and list its variables. The
+/// resolved path is the one shown to the user and filled from.
+fn read_template(template: &str) -> Result<(String, Vec<String>), String> {
+    let path = db::check_template_path(Path::new(template))?;
+    let content =
+        std::fs::read_to_string(&path).map_err(|e| format!("Cannot read template: {}", e))?;
+    let variables = dotenv::parse(&content)
+        .entries()
+        .map(|(key, _)| key.to_string())
+        .collect();
+    Ok((path.to_string_lossy().into_owned(), variables))
+}
+
+fn parse_arguments<T: DeserializeOwned>(arguments: Value) -> Result<T, String> {
+    serde_json::from_value(arguments).map_err(|e| format!("Invalid arguments: {}", e))
+}
+
+/// Line added to a write message when the file is not protected by
+/// `.gitignore`, or has just been added to it
+fn git_note(report: &WriteEnvReport, path: &str) -> String {
+    match report.git {
+        GitCheck::NotIgnored => format!(
+            "\nWarning: {} is inside a git repository and not covered by .gitignore",
+            path
+        ),
+        GitCheck::Tracked => format!(
+            "\nWarning: {} is tracked by git, so changes to it will be committed",
+            path
+        ),
+        GitCheck::AddedToGitignore => format!("\nAdded {} to .gitignore", path),
+        GitCheck::NotInRepository | GitCheck::Ignored => String::new(),
+    }
+}
+
+/// Make sure the vault is unlocked before asking the user to approve a
+/// write, which could not go through otherwise
+fn ensure_unlocked() -> Result<(), String> {
+    if !db::vault_status()?.unlocked {
+        return Err(vault::VAULT_LOCKED.to_string());
+    }
+    Ok(())
+}
+
+/// Tool definitions advertised in `tools/list`
+fn tools() -> Value {
+    let git_policy = json!({
+        "type": "string",
+        "enum": ["refuse", "warn", "add_to_gitignore"],
+        "description": "What to do if the file is inside a git repository and not ignored: refuse (default), warn and write anyway, or add it to .gitignore first",
+    });
+    let aliases = json!({
+        "type": "object",
+        "additionalProperties": { "type": "string" },
+        "description": "Optional extra variables mapped to the secret that provides each, e.g. { \"VITE_OPENAI_KEY\": \"OPENAI_API_KEY\" }",
+    });
+    let prefix = json!({
+        "type": "string",
+        "description": "Optional prefix for the variable names of keys and tagged secrets (not aliases), e.g. VITE_",
+    });
+    let format = json!({
+        "type": "string",
+        "enum": ["dotenv", "shell", "json", "yaml", "toml", "docker", "systemd", "direnv", "kubernetes", "sealedsecret"],
+        "description": "Optional output format; inferred from the file name if omitted (.json, .yaml/.yml, .toml, .sh, .envrc), otherwise dotenv. Merge mode only supports dotenv",
+    });
+    let kubernetes = json!({
+        "type": "object",
+        "properties": {
+            "name": {
+                "type": "string",
+                "description": "Name of the Secret (lowercase letters, digits, '-' and '.')",
+            },
+            "namespace": {
+                "type": "string",
+                "description": "Optional namespace (lowercase letters, digits and '-')",
+            },
+            "labels": {
+                "type": "object",
+                "additionalProperties": { "type": "string" },
+                "description": "Optional labels for the Secret's metadata",
+            },
+        },
+        "required": ["name"],
+        "description": "Required for the kubernetes and sealedsecret formats. sealedsecret writes placeholders for kubeseal instead of values",
+    });
+
+    json!([
+        {
+            "name": "search_secrets",
+            "description": "Search for secrets by name or description. Returns names and descriptions only, never values. Use this to find secrets before writing them to a .env file.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "query": {
+                        "type": "string",
+                        "description": "Search query to match against secret names, descriptions and tags. Use tag:name to only return secrets with that tag",
+                    },
+                    "project": {
+                        "type": "string",
+                        "description": "Optional project name; limits results to that project and global secrets",
+                    },
+                },
+                "required": ["query"],
+            },
+        },
+        {
+            "name": "write_env",
+            "description": "Write specified secrets to a .env file. Values are retrieved securely and never exposed to the AI. The file is created with restricted permissions (600).",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "keys": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Secret names to include in the .env file",
+                    },
+                    "path": {
+                        "type": "string",
+                        "description": "Absolute path where the .env file should be written",
+                    },
+                    "project": {
+                        "type": "string",
+                        "description": "Optional project name; its secrets take precedence over global ones",
+                    },
+                    "environment": {
+                        "type": "string",
+                        "description": "Optional environment (e.g. development, staging, production); secrets without a value for it use their default",
+                    },
+                    "tag": {
+                        "type": "string",
+                        "description": "Optional tag, without spaces or commas; every secret carrying it is written in addition to keys",
+                    },
+                    "mode": {
+                        "type": "string",
+                        "enum": ["overwrite", "merge"],
+                        "description": "overwrite (default) replaces the file; merge updates only these keys in an existing file, keeping comments and other variables",
+                    },
+                    "format": format,
+                    "kubernetes": kubernetes,
+                    "git": git_policy,
+                    "aliases": aliases,
+                    "prefix": prefix,
+                },
+                "required": ["keys", "path"],
+            },
+        },
+        {
+            "name": "fill_env_from_template",
+            "description": "Create a .env file from a template such as .env.example in one call. Each variable is matched to a secret by alias or name and gets its value; unmatched variables keep the template's default. Comments and ordering are kept, and values are never exposed to the AI.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "template": {
+                        "type": "string",
+                        "description": "Absolute path of the template, e.g. /path/to/project/.env.example",
+                    },
+                    "path": {
+                        "type": "string",
+                        "description": "Absolute path where the .env file should be written",
+                    },
+                    "project": {
+                        "type": "string",
+                        "description": "Optional project name; its secrets take precedence over global ones",
+                    },
+                    "environment": {
+                        "type": "string",
+                        "description": "Optional environment; secrets without a value for it use their default",
+                    },
+                    "aliases": {
+                        "type": "object",
+                        "additionalProperties": { "type": "string" },
+                        "description": "Optional template variables mapped to the secret that provides each, e.g. { \"VITE_OPENAI_KEY\": \"OPENAI_API_KEY\" }",
+                    },
+                    "prefix": {
+                        "type": "string",
+                        "description": "Optional prefix to strip from template variables when matching secret names, e.g. VITE_",
+                    },
+                    "mode": {
+                        "type": "string",
+                        "enum": ["overwrite", "merge"],
+                        "description": "overwrite (default) writes the filled template; merge fills variables into an existing file, keeping its other lines and values",
+                    },
+                    "git": git_policy,
+                },
+                "required": ["template", "path"],
+            },
+        },
+        {
+            "name": "diff_env",
+            "description": "Preview write_env without writing: lists which keys would be added, changed, unchanged, removed (overwrite only), not comparable (existing files that are not dotenv) or missing. Values are compared by hash and never returned.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "keys": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Secret names to include in the .env file",
+                    },
+                    "path": {
+                        "type": "string",
+                        "description": "Absolute path of the .env file to compare against",
+                    },
+                    "project": {
+                        "type": "string",
+                        "description": "Optional project name; its secrets take precedence over global ones",
+                    },
+                    "environment": {
+                        "type": "string",
+                        "description": "Optional environment; secrets without a value for it use their default",
+                    },
+                    "tag": {
+                        "type": "string",
+                        "description": "Optional tag, without spaces or commas; every secret carrying it is included in addition to keys",
+                    },
+                    "mode": {
+                        "type": "string",
+                        "enum": ["overwrite", "merge"],
+                        "description": "Mode the write would use; only overwrite removes other variables",
+                    },
+                    "format": format,
+                    "kubernetes": kubernetes,
+                    "git": {
+                        "type": "string",
+                        "enum": ["refuse", "warn", "add_to_gitignore"],
+                        "description": "Git policy the write would use; refuse (default) fails the preview if the file would not be ignored",
+                    },
+                    "aliases": aliases,
+                    "prefix": prefix,
+                },
+                "required": ["keys", "path"],
+            },
+        },
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TestApp;
+    use std::thread;
+    use std::time::Duration;
+
+    fn request(method: &str, params: Value) -> String {
+        json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }).to_string()
+    }
+
+    #[test]
+    fn initialize_agrees_on_a_known_version_or_offers_the_newest() {
+        let mut session = Session::default();
+        for (requested, agreed) in [
+            ("2025-03-26", "2025-03-26"),
+            ("2024-11-05", "2024-11-05"),
+            ("1999-01-01", PROTOCOL_VERSIONS[0]),
+        ] {
+            let response = session
+                .handle(&request(
+                    "initialize",
+                    json!({
+                        "protocolVersion": requested,
+                        "clientInfo": { "name": "cursor" },
+                    }),
+                ))
+                .unwrap();
+            assert_eq!(response["result"]["protocolVersion"], agreed);
+        }
+        assert_eq!(session.client, "cursor");
+    }
+
+    #[test]
+    fn tools_are_listed_with_input_schemas() {
+        let response = Session::default()
+            .handle(&request("tools/list", Value::Null))
+            .unwrap();
+
+        let tools = response["result"]["tools"].as_array().unwrap();
+        let names: Vec<&str> = tools.iter().filter_map(|t| t["name"].as_str()).collect();
+        assert_eq!(names.len(), tools.len());
+        for name in ["search_secrets", "write_env", "fill_env_from_template"] {
+            assert!(names.contains(&name), "{} is not listed", name);
+        }
+        assert!(tools
+            .iter()
+            .all(|tool| tool["inputSchema"]["type"] == "object"));
+    }
+
+    #[test]
+    fn notifications_and_responses_get_no_response() {
+        let mut session = Session::default();
+        let notification = json!({ "jsonrpc": "2.0", "method": "notifications/initialized" });
+        let response = json!({ "jsonrpc": "2.0", "id": 4, "result": {} });
+
+        assert_eq!(session.handle(&notification.to_string()), None);
+        assert_eq!(session.handle(&response.to_string()), None);
+    }
+
+    #[test]
+    fn malformed_json_is_a_parse_error() {
+        let response = Session::default().handle("{\"jsonrpc\": ").unwrap();
+
+        assert_eq!(response["error"]["code"], PARSE_ERROR);
+        assert_eq!(response["id"], Value::Null);
+    }
+
+    #[test]
+    fn unknown_method_is_not_found() {
+        let response = Session::default()
+            .handle(&request("resources/list", Value::Null))
+            .unwrap();
+
+        assert_eq!(response["error"]["code"], METHOD_NOT_FOUND);
+        assert_eq!(response["id"], 1);
+    }
+
+    #[test]
+    fn cancelling_a_call_denies_its_approval() {
+        let app = TestApp::new();
+        let path = app.file(".env").to_string_lossy().into_owned();
+        let mut session = Session::default();
+
+        let call = {
+            let mut session = session.clone();
+            let request = json!({
+                "jsonrpc": "2.0",
+                "id": 7,
+                "method": "tools/call",
+                "params": {
+                    "name": "write_env",
+                    "arguments": { "keys": ["API_KEY"], "path": path },
+                },
+            });
+            thread::spawn(move || session.handle(&request.to_string()).unwrap())
+        };
+        while db::list_write_approvals().unwrap().is_empty() {
+            thread::sleep(Duration::from_millis(20));
+        }
+
+        let cancel = json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/cancelled",
+            "params": { "requestId": 7 },
+        });
+        assert_eq!(session.handle(&cancel.to_string()), None);
+
+        let response = call.join().unwrap();
+        assert_eq!(response["result"]["isError"], true);
+        assert!(db::list_write_approvals().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,312 @@
+use crate::db;
+use crate::mcp_http::{HttpConnection, SESSION_HEADER};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::sync::{Mutex, MutexGuard};
+use std::thread;
+
+/// JSON-RPC error code for requests the app could not answer
+const SERVER_ERROR: i64 = -32000;
+
+/// Shown when the app is closed or its endpoint is stopped
+const NOT_RUNNING: &str = "Secret MCP is not serving MCP. Open the app, and start the HTTP endpoint under MCP Setup if it is stopped";
+
+/// Relay newline-delimited JSON-RPC on `input` to the desktop app's HTTP
+/// endpoint until `input` is closed. The app holds the unlocked vault and
+/// asks the user about writes, so this process never needs the master
+/// password.
+pub fn serve(input: impl BufRead, output: impl Write + Send) -> Result<(), String> {
+    let bridge = Bridge::default();
+    let output = Mutex::new(output);
+    thread::scope(|scope| {
+        for line in input.lines() {
+            let line = line.map_err(|e| e.to_string())?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            // A request may wait for the user to approve a write, so each
+            // gets a thread and pings and cancellations still get through.
+            // `initialize` and notifications are relayed in order.
+            let message = serde_json::from_str::<Value>(&line).unwrap_or_default();
+            let method = message.get("method").and_then(Value::as_str);
+            if message.get("id").is_some() && method.is_some_and(|m| m != "initialize") {
+                let (bridge, output) = (&bridge, &output);
+                // Nothing is left to tell the client if its output is closed
+                scope.spawn(move || relay(bridge, &line, output));
+            } else {
+                relay(&bridge, &line, &output)?;
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Forward one message and write the response, if any, to `output`
+fn relay(bridge: &Bridge, line: &str, output: &Mutex<impl Write>) -> Result<(), String> {
+    let response = match bridge.forward(line) {
+        Ok(response) => response,
+        Err(e) => error_response(line, &e),
+    };
+    if let Some(response) = response {
+        let mut output = output.lock().map_err(|e| e.to_string())?;
+        writeln!(output, "{}", response).map_err(|e| e.to_string())?;
+        output.flush().map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+#[derive(Default)]
+struct Bridge {
+    state: Mutex<BridgeState>,
+}
+
+#[derive(Default)]
+struct BridgeState {
+    /// Session the app issued for the client's `initialize`
+    session_id: Option<String>,
+    /// The client's `initialize`, replayed to get a new session when the app
+    /// has restarted since
+    initialize: Option<String>,
+    /// Requests being relayed, by ID, and whether the client has cancelled
+    /// each so no longer wants its response
+    in_flight: HashMap<String, bool>,
+}
+
+impl Bridge {
+    /// Send one message to the app, returning its response if it has one
+    fn forward(&self, message: &str) -> Result<Option<String>, String> {
+        let parsed = serde_json::from_str::<Value>(message).unwrap_or_default();
+        let method = parsed.get("method").and_then(Value::as_str);
+        let initialize = method == Some("initialize");
+
+        let session_id = {
+            let mut state = self.lock()?;
+            if initialize {
+                state.initialize = Some(message.to_string());
+                state.session_id = None;
+            }
+            if let Some(id) = parsed.get("id").filter(|_| method.is_some()) {
+                state.in_flight.insert(id.to_string(), false);
+            }
+            if method == Some("notifications/cancelled") {
+                if let Some(request_id) = parsed.pointer("/params/requestId") {
+                    if let Some(cancelled) = state.in_flight.get_mut(&request_id.to_string()) {
+                        *cancelled = true;
+                    }
+                }
+            }
+            state.session_id.clone()
+        };
+
+        let response = self.relay_in_session(message, initialize, session_id);
+        let cancelled = match parsed.get("id").filter(|_| method.is_some()) {
+            Some(id) => self.lock()?.in_flight.remove(&id.to_string()),
+            None => None,
+        };
+        if cancelled == Some(true) {
+            return Ok(None);
+        }
+        response
+    }
+
+    /// POST `message` in session `session_id`, starting a new session if the
+    /// app no longer knows it
+    fn relay_in_session(
+        &self,
+        message: &str,
+        initialize: bool,
+        session_id: Option<String>,
+    ) -> Result<Option<String>, String> {
+        let (status, body) = match post(message, session_id.as_deref())? {
+            // The app no longer knows the session: start a new one
+            (404, body, _) if !initialize => match self.reinitialize(session_id)? {
+                Some(session_id) => {
+                    let (status, body, _) = post(message, Some(&session_id))?;
+                    (status, body)
+                }
+                None => (404, body),
+            },
+            (status, body, new_session_id) => {
+                if initialize && new_session_id.is_some() {
+                    self.lock()?.session_id = new_session_id;
+                }
+                (status, body)
+            }
+        };
+        match status {
+            200 => Ok(Some(body.trim().to_string())),
+            202 => Ok(None),
+            _ => Err(format!(
+                "Secret MCP refused the request ({}): {}",
+                status,
+                body.trim()
+            )),
+        }
+    }
+
+    /// Replace the session `stale`, unless another request already has,
+    /// returning the new one. None if the client never initialized.
+    fn reinitialize(&self, stale: Option<String>) -> Result<Option<String>, String> {
+        let mut state = self.lock()?;
+        if state.session_id.is_some() && state.session_id != stale {
+            return Ok(state.session_id.clone());
+        }
+        let Some(initialize) = state.initialize.clone() else {
+            return Ok(None);
+        };
+        match post(&initialize, None)? {
+            (200, _, Some(session_id)) => {
+                state.session_id = Some(session_id.clone());
+                Ok(Some(session_id))
+            }
+            (status, body, _) => Err(format!(
+                "Secret MCP refused a new session ({}): {}",
+                status,
+                body.trim()
+            )),
+        }
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, BridgeState>, String> {
+        self.state.lock().map_err(|e| e.to_string())
+    }
+}
+
+/// POST `message` to the endpoint the app published, in session
+/// `session_id`, returning the status, body and the session ID the app sent
+/// back. The file is read each time, as the URL and token change whenever
+/// the endpoint restarts.
+fn post(message: &str, session_id: Option<&str>) -> Result<(u16, String, Option<String>), String> {
+    let content =
+        std::fs::read(db::get_mcp_http_connection_path()).map_err(|_| NOT_RUNNING.to_string())?;
+    let connection: HttpConnection = serde_json::from_slice(&content).map_err(|e| e.to_string())?;
+
+    let mut request = ureq::post(&connection.url)
+        .set("Authorization", &format!("Bearer {}", connection.token))
+        .set("Content-Type", "application/json")
+        .set("Accept", "application/json, text/event-stream");
+    if let Some(session_id) = session_id {
+        request = request.set(SESSION_HEADER, session_id);
+    }
+    let response = match request.send_string(message) {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+        Err(ureq::Error::Transport(_)) => return Err(NOT_RUNNING.to_string()),
+    };
+
+    let session_id = response.header(SESSION_HEADER).map(String::from);
+    let status = response.status();
+    let body = response.into_string().map_err(|e| e.to_string())?;
+    Ok((status, body, session_id))
+}
+
+/// Report `error` to the client if `message` is a request; notifications
+/// cannot be answered
+fn error_response(message: &str, error: &str) -> Option<String> {
+    let id = serde_json::from_str::<Value>(message)
+        .ok()
+        .and_then(|message| message.get("id").cloned());
+    let Some(id) = id else {
+        eprintln!("{}", error);
+        return None;
+    };
+    Some(
+        json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": SERVER_ERROR, "message": error },
+        })
+        .to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TestApp;
+    use tiny_http::{Header, Response, Server};
+
+    /// Serve as the app would, forgetting the first session once it has
+    /// been used, as after a restart. Returns each request's method and
+    /// session ID.
+    fn stub_app() -> thread::JoinHandle<Vec<(String, Option<String>)>> {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let connection = HttpConnection {
+            url: format!("http://{}/mcp", server.server_addr()),
+            token: "token".to_string(),
+        };
+        std::fs::write(
+            db::get_mcp_http_connection_path(),
+            serde_json::to_vec(&connection).unwrap(),
+        )
+        .unwrap();
+
+        thread::spawn(move || {
+            let mut seen = Vec::new();
+            let mut sessions = 0;
+            while seen.len() < 4 {
+                let mut request = server.recv().unwrap();
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                let message: Value = serde_json::from_str(&body).unwrap();
+                let method = message["method"].as_str().unwrap().to_string();
+                let session_id = request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv(SESSION_HEADER))
+                    .map(|h| h.value.to_string());
+                seen.push((method.clone(), session_id.clone()));
+
+                let result = json!({ "jsonrpc": "2.0", "id": message["id"], "result": {} });
+                let response = if method == "initialize" {
+                    sessions += 1;
+                    let header = format!("{}: session-{}", SESSION_HEADER, sessions);
+                    Response::from_string(result.to_string())
+                        .with_header(header.parse::<Header>().unwrap())
+                } else if session_id.as_deref() == Some("session-1") {
+                    Response::from_string("Session not found").with_status_code(404)
+                } else {
+                    Response::from_string(result.to_string())
+                };
+                request.respond(response).unwrap();
+            }
+            seen
+        })
+    }
+
+    #[test]
+    fn starts_a_new_session_when_the_app_has_forgotten_it() {
+        let _app = TestApp::new();
+        let app = stub_app();
+        let input = [
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }),
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/list" }),
+        ]
+        .map(|message| message.to_string())
+        .join("\n");
+
+        let mut output = Vec::new();
+        serve(input.as_bytes(), &mut output).unwrap();
+
+        let responses: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[1]["id"], 2);
+        assert!(responses[1].get("result").is_some());
+
+        let session = |id: &str| Some(id.to_string());
+        assert_eq!(
+            app.join().unwrap(),
+            [
+                ("initialize".to_string(), None),
+                ("tools/list".to_string(), session("session-1")),
+                ("initialize".to_string(), None),
+                ("tools/list".to_string(), session("session-2")),
+            ]
+        );
+    }
+}
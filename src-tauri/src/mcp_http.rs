@@ -0,0 +1,536 @@
+use crate::atomic_file;
+use crate::db;
+use crate::mcp::Session;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::OsRng;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// Port used when none is given
+pub const DEFAULT_PORT: u16 = 8787;
+
+/// Path of the MCP endpoint
+const ENDPOINT: &str = "/mcp";
+
+/// Largest request body accepted, in bytes
+const MAX_BODY_LEN: u64 = 1024 * 1024;
+
+/// Random bytes in a bearer token
+const TOKEN_LEN: usize = 32;
+
+/// Random bytes in a session ID
+const SESSION_ID_LEN: usize = 16;
+
+/// Header carrying the session ID, issued in the `initialize` response
+pub const SESSION_HEADER: &str = "Mcp-Session-Id";
+
+/// Sessions unused for this long are forgotten; their clients start new ones
+const SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+
+/// Most sessions kept; a new one replaces the least recently used
+const MAX_SESSIONS: usize = 64;
+
+/// Most requests handled at once, each on its own thread; more are refused
+/// until one finishes
+const MAX_ACTIVE_REQUESTS: usize = 32;
+
+type HttpResponse = Response<Cursor<Vec<u8>>>;
+
+/// A client's session and when it last made a request
+struct SessionEntry {
+    session: Session,
+    last_used: Instant,
+}
+
+/// Sessions of the connected clients, by session ID
+type Sessions = Mutex<HashMap<String, SessionEntry>>;
+
+/// State of the HTTP endpoint, shown in the desktop app
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HttpStatus {
+    pub running: bool,
+    /// Endpoint URL, e.g. `http://127.0.0.1:8787/mcp`
+    pub url: Option<String>,
+    /// Bearer token clients must send; a new one is generated on each start
+    pub token: Option<String>,
+}
+
+/// Where to reach the endpoint, published in a file only the user can read
+/// so `secret-mcp-server` can relay to it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HttpConnection {
+    pub url: String,
+    pub token: String,
+}
+
+struct RunningServer {
+    server: Arc<Server>,
+    thread: JoinHandle<()>,
+    url: String,
+    token: String,
+}
+
+/// The endpoint, while it is running
+static SERVER: Lazy<Mutex<Option<RunningServer>>> = Lazy::new(|| Mutex::new(None));
+
+/// Serve MCP over streamable HTTP on `127.0.0.1:port` (0 picks a free port)
+/// with a fresh bearer token, and publish both for `secret-mcp-server`. Tool
+/// calls use the vault as the app has it, so they fail while it is locked.
+pub fn start(port: u16) -> Result<HttpStatus, String> {
+    let mut running = SERVER.lock().map_err(|e| e.to_string())?;
+    if running.is_some() {
+        return Err("MCP HTTP server is already running".to_string());
+    }
+
+    let server = Server::http(("127.0.0.1", port)).map_err(|e| e.to_string())?;
+    let addr = server
+        .server_addr()
+        .to_ip()
+        .ok_or("MCP HTTP server is not listening on an IP address")?;
+    let server = Arc::new(server);
+    let token = random_hex(TOKEN_LEN);
+    let url = format!("http://{}{}", addr, ENDPOINT);
+
+    let connection = HttpConnection {
+        url: url.clone(),
+        token: token.clone(),
+    };
+    let content = serde_json::to_vec(&connection).map_err(|e| e.to_string())?;
+    atomic_file::write(&db::get_mcp_http_connection_path(), &content)?;
+
+    let thread = {
+        let server = Arc::clone(&server);
+        let token = token.clone();
+        // Each request gets a thread, as writes wait for the user's approval
+        let sessions: Arc<Sessions> = Arc::default();
+        let active = Arc::new(AtomicUsize::new(0));
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                if active.fetch_add(1, Ordering::SeqCst) >= MAX_ACTIVE_REQUESTS {
+                    active.fetch_sub(1, Ordering::SeqCst);
+                    let busy = text_response(503, "Too many requests")
+                        .with_header(header("Retry-After", "1"));
+                    let _ = request.respond(busy);
+                    continue;
+                }
+                let token = token.clone();
+                let sessions = Arc::clone(&sessions);
+                let active = Arc::clone(&active);
+                thread::spawn(move || {
+                    respond(request, &token, &sessions);
+                    active.fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+        })
+    };
+
+    *running = Some(RunningServer {
+        server,
+        thread,
+        url,
+        token,
+    });
+    Ok(status_of(running.as_ref()))
+}
+
+/// Stop the endpoint, if it is running; its token and sessions stop working
+pub fn stop() -> Result<HttpStatus, String> {
+    let running = SERVER.lock().map_err(|e| e.to_string())?.take();
+    if let Some(running) = running {
+        if let Err(e) = std::fs::remove_file(db::get_mcp_http_connection_path()) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                return Err(e.to_string());
+            }
+        }
+        running.server.unblock();
+        running
+            .thread
+            .join()
+            .map_err(|_| "MCP HTTP server thread panicked".to_string())?;
+    }
+    Ok(HttpStatus::default())
+}
+
+/// Get the endpoint's state, including its URL and token while running
+pub fn status() -> Result<HttpStatus, String> {
+    let running = SERVER.lock().map_err(|e| e.to_string())?;
+    Ok(status_of(running.as_ref()))
+}
+
+fn status_of(running: Option<&RunningServer>) -> HttpStatus {
+    match running {
+        Some(running) => HttpStatus {
+            running: true,
+            url: Some(running.url.clone()),
+            token: Some(running.token.clone()),
+        },
+        None => HttpStatus::default(),
+    }
+}
+
+/// `len` random bytes, hex-encoded
+fn random_hex(len: usize) -> String {
+    let mut bytes = vec![0u8; len];
+    OsRng.fill_bytes(&mut bytes);
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn respond(mut request: Request, token: &str, sessions: &Sessions) {
+    let response = match check_request(&request, token) {
+        Err(response) => response,
+        Ok(()) if *request.method() == Method::Post => handle_post(&mut request, sessions),
+        Ok(()) if *request.method() == Method::Delete => end_session(&request, sessions),
+        // No server-initiated stream (GET)
+        Ok(()) => {
+            text_response(405, "Method not allowed").with_header(header("Allow", "POST, DELETE"))
+        }
+    };
+    let _ = request.respond(response);
+}
+
+/// Only the endpoint, only from local pages or non-browser clients, and
+/// only with the token
+fn check_request(request: &Request, token: &str) -> Result<(), HttpResponse> {
+    let path = request.url().split('?').next().unwrap_or_default();
+    if path != ENDPOINT {
+        return Err(text_response(404, "Not found"));
+    }
+
+    // Browsers send Origin; a remote site reaching us through DNS rebinding
+    // would carry its own
+    if let Some(origin) = header_value(request, "Origin") {
+        if !is_local_origin(origin) {
+            return Err(text_response(403, "Forbidden origin"));
+        }
+    }
+
+    let authorized = header_value(request, "Authorization")
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|given| tokens_match(given.trim().as_bytes(), token.as_bytes()));
+    if !authorized {
+        return Err(
+            text_response(401, "Unauthorized").with_header(header("WWW-Authenticate", "Bearer"))
+        );
+    }
+    Ok(())
+}
+
+fn handle_post(request: &mut Request, sessions: &Sessions) -> HttpResponse {
+    let mut body = String::new();
+    let read = request
+        .as_reader()
+        .take(MAX_BODY_LEN + 1)
+        .read_to_string(&mut body);
+    if read.is_err() {
+        return text_response(400, "Request body must be UTF-8");
+    }
+    if body.len() as u64 > MAX_BODY_LEN {
+        return text_response(413, "Request body too large");
+    }
+
+    // `initialize` starts a session; everything else needs the ID it issued
+    let initialize = serde_json::from_str::<Value>(&body)
+        .is_ok_and(|message| message.get("method").and_then(Value::as_str) == Some("initialize"));
+    let (session_id, mut session) = if initialize {
+        (random_hex(SESSION_ID_LEN), Session::default())
+    } else {
+        let Some(session_id) = header_value(request, SESSION_HEADER) else {
+            return text_response(400, "Missing Mcp-Session-Id header");
+        };
+        // Not held while handling, which may wait for the user
+        let Ok(mut sessions) = sessions.lock() else {
+            return text_response(500, "Session unavailable");
+        };
+        let Some(session) = use_session(&mut sessions, session_id, Instant::now()) else {
+            return text_response(404, "Session not found");
+        };
+        (session_id.to_string(), session)
+    };
+
+    let response = session.handle(&body);
+    let Ok(mut sessions) = sessions.lock() else {
+        return text_response(500, "Session unavailable");
+    };
+    if initialize {
+        add_session(&mut sessions, session_id.clone(), session, Instant::now());
+    } else if let Some(stored) = sessions.get_mut(&session_id) {
+        // Unless the client ended it meanwhile
+        stored.session = session;
+    }
+    drop(sessions);
+
+    let Some(message) = response else {
+        // Notifications and responses are only acknowledged
+        return Response::from_data(Vec::new()).with_status_code(202);
+    };
+    let session_header = header(SESSION_HEADER, &session_id);
+
+    // Clients must accept both; a stream of one event serves those that
+    // only take SSE
+    let accept = header_value(request, "Accept").unwrap_or_default();
+    if accept.contains("text/event-stream") && !accept.contains("application/json") {
+        Response::from_string(format!("event: message\ndata: {}\n\n", message))
+            .with_header(header("Content-Type", "text/event-stream"))
+            .with_header(header("Cache-Control", "no-cache"))
+            .with_header(session_header)
+    } else {
+        Response::from_string(message.to_string())
+            .with_header(header("Content-Type", "application/json"))
+            .with_header(session_header)
+    }
+}
+
+/// Get a copy of session `id` to handle a request in, marking it used, unless
+/// it has been idle too long
+fn use_session(
+    sessions: &mut HashMap<String, SessionEntry>,
+    id: &str,
+    now: Instant,
+) -> Option<Session> {
+    let entry = sessions.get_mut(id)?;
+    if now.duration_since(entry.last_used) > SESSION_IDLE_TIMEOUT {
+        sessions.remove(id);
+        return None;
+    }
+    entry.last_used = now;
+    Some(entry.session.clone())
+}
+
+/// Keep a new session, first dropping idle ones and, if there are still too
+/// many, the least recently used
+fn add_session(
+    sessions: &mut HashMap<String, SessionEntry>,
+    id: String,
+    session: Session,
+    now: Instant,
+) {
+    sessions.retain(|_, entry| now.duration_since(entry.last_used) <= SESSION_IDLE_TIMEOUT);
+    if sessions.len() >= MAX_SESSIONS {
+        let oldest = sessions
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(id, _)| id.clone());
+        if let Some(oldest) = oldest {
+            sessions.remove(&oldest);
+        }
+    }
+    sessions.insert(
+        id,
+        SessionEntry {
+            session,
+            last_used: now,
+        },
+    );
+}
+
+/// End the session named by the request's `Mcp-Session-Id`
+fn end_session(request: &Request, sessions: &Sessions) -> HttpResponse {
+    let Some(session_id) = header_value(request, SESSION_HEADER) else {
+        return text_response(400, "Missing Mcp-Session-Id header");
+    };
+    let Ok(mut sessions) = sessions.lock() else {
+        return text_response(500, "Session unavailable");
+    };
+    match sessions.remove(session_id) {
+        Some(_) => Response::from_data(Vec::new()).with_status_code(204),
+        None => text_response(404, "Session not found"),
+    }
+}
+
+fn header_value<'a>(request: &'a Request, name: &'static str) -> Option<&'a str> {
+    request
+        .headers()
+        .iter()
+        .find(|h| h.field.equiv(name))
+        .map(|h| h.value.as_str())
+}
+
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name.as_bytes(), value.as_bytes()).expect("valid header")
+}
+
+fn text_response(status: u16, text: &str) -> HttpResponse {
+    Response::from_string(text).with_status_code(status)
+}
+
+fn is_local_origin(origin: &str) -> bool {
+    let Some(host) = origin
+        .strip_prefix("http://")
+        .or_else(|| origin.strip_prefix("https://"))
+    else {
+        return false;
+    };
+    let host = match host.rsplit_once(':') {
+        Some((host, port)) if port.chars().all(|c| c.is_ascii_digit()) => host,
+        _ => host,
+    };
+    matches!(host, "127.0.0.1" | "localhost" | "[::1]")
+}
+
+/// Compare in constant time, so response timing does not leak the token
+fn tokens_match(given: &[u8], expected: &[u8]) -> bool {
+    given.len() == expected.len()
+        && given
+            .iter()
+            .zip(expected)
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TestApp;
+    use serde_json::json;
+
+    /// The endpoint on a free port, stopped when dropped
+    struct Endpoint {
+        url: String,
+        token: String,
+    }
+
+    impl Endpoint {
+        fn start() -> Self {
+            let status = start(0).unwrap();
+            Endpoint {
+                url: status.url.unwrap(),
+                token: status.token.unwrap(),
+            }
+        }
+
+        /// POST `body` with the token and `headers`, returning the status
+        /// and the session ID sent back
+        fn post(&self, headers: &[(&str, &str)], body: &Value) -> (u16, Option<String>) {
+            let authorization = format!("Bearer {}", self.token);
+            let mut request = ureq::post(&self.url).set("Authorization", &authorization);
+            for (name, value) in headers {
+                request = request.set(name, value);
+            }
+            match request.send_string(&body.to_string()) {
+                Ok(response) | Err(ureq::Error::Status(_, response)) => (
+                    response.status(),
+                    response.header(SESSION_HEADER).map(String::from),
+                ),
+                Err(e) => panic!("{}", e),
+            }
+        }
+
+        fn initialize(&self) -> String {
+            let (status, session_id) = self.post(&[], &request("initialize"));
+            assert_eq!(status, 200);
+            session_id.unwrap()
+        }
+    }
+
+    impl Drop for Endpoint {
+        fn drop(&mut self) {
+            let _ = stop();
+        }
+    }
+
+    fn request(method: &str) -> Value {
+        json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": {} })
+    }
+
+    #[test]
+    fn requests_without_the_token_are_unauthorized() {
+        let _app = TestApp::new();
+        let endpoint = Endpoint::start();
+
+        let body = request("initialize").to_string();
+        // The token itself, without the Bearer scheme, is not enough either
+        for authorization in [None, Some("Bearer wrong"), Some(endpoint.token.as_str())] {
+            let mut request = ureq::post(&endpoint.url);
+            if let Some(authorization) = authorization {
+                request = request.set("Authorization", authorization);
+            }
+            match request.send_string(&body) {
+                Err(ureq::Error::Status(status, _)) => assert_eq!(status, 401),
+                other => panic!("{:?} was accepted: {:?}", authorization, other.map(|_| ())),
+            }
+        }
+    }
+
+    #[test]
+    fn requests_from_other_origins_are_forbidden() {
+        let _app = TestApp::new();
+        let endpoint = Endpoint::start();
+
+        let foreign = endpoint.post(
+            &[("Origin", "https://evil.example")],
+            &request("initialize"),
+        );
+        let local = endpoint.post(
+            &[("Origin", "http://localhost:1420")],
+            &request("initialize"),
+        );
+
+        assert_eq!(foreign.0, 403);
+        assert_eq!(local.0, 200);
+    }
+
+    #[test]
+    fn requests_need_a_known_session() {
+        let _app = TestApp::new();
+        let endpoint = Endpoint::start();
+        endpoint.initialize();
+
+        let missing = endpoint.post(&[], &request("tools/list"));
+        let unknown = endpoint.post(&[(SESSION_HEADER, "unknown")], &request("tools/list"));
+
+        assert_eq!(missing.0, 400);
+        assert_eq!(unknown.0, 404);
+    }
+
+    #[test]
+    fn notifications_are_accepted_without_a_response() {
+        let _app = TestApp::new();
+        let endpoint = Endpoint::start();
+        let session_id = endpoint.initialize();
+
+        let notification = json!({ "jsonrpc": "2.0", "method": "notifications/initialized" });
+        let (status, _) = endpoint.post(&[(SESSION_HEADER, &session_id)], &notification);
+
+        assert_eq!(status, 202);
+    }
+
+    #[test]
+    fn idle_sessions_are_forgotten() {
+        let mut sessions = HashMap::new();
+        let start = Instant::now();
+        add_session(&mut sessions, "a".to_string(), Session::default(), start);
+
+        let later = start + SESSION_IDLE_TIMEOUT;
+        assert!(use_session(&mut sessions, "a", later).is_some());
+        let too_late = later + SESSION_IDLE_TIMEOUT + Duration::from_secs(1);
+        assert!(use_session(&mut sessions, "a", too_late).is_none());
+        assert!(sessions.is_empty());
+    }
+
+    #[test]
+    fn least_recently_used_session_makes_room() {
+        let mut sessions = HashMap::new();
+        let start = Instant::now();
+        for i in 0..MAX_SESSIONS {
+            let at = start + Duration::from_secs(i as u64);
+            add_session(&mut sessions, i.to_string(), Session::default(), at);
+        }
+        let now = start + Duration::from_secs(MAX_SESSIONS as u64);
+        use_session(&mut sessions, "0", now);
+
+        add_session(&mut sessions, "new".to_string(), Session::default(), now);
+
+        assert_eq!(sessions.len(), MAX_SESSIONS);
+        assert!(sessions.contains_key("0"));
+        assert!(!sessions.contains_key("1"));
+        assert!(sessions.contains_key("new"));
+    }
+}
@@ -0,0 +1,409 @@
+use rusqlite::Connection;
+
+/// Ordered schema migrations. `MIGRATIONS[i]` upgrades a database from
+/// `user_version` i to i + 1. Never edit a released step; append a new one.
+const MIGRATIONS: &[&str] = &[
+    // 1: initial schema. Uses IF NOT EXISTS because databases created before
+    // versioning already have some of these tables at user_version 0.
+    "CREATE TABLE IF NOT EXISTS secrets (
+        id TEXT PRIMARY KEY,
+        name TEXT UNIQUE NOT NULL,
+        description TEXT,
+        value BLOB NOT NULL,
+        created_at INTEGER NOT NULL,
+        updated_at INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS vault (
+        id INTEGER PRIMARY KEY CHECK (id = 1),
+        kdf_salt BLOB NOT NULL,
+        kdf_m_cost INTEGER NOT NULL,
+        kdf_t_cost INTEGER NOT NULL,
+        kdf_p_cost INTEGER NOT NULL,
+        wrapped_key BLOB NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS settings (
+        key TEXT PRIMARY KEY,
+        value TEXT NOT NULL
+    );",
+    // 2: history of superseded secret values
+    "CREATE TABLE secret_versions (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        secret_id TEXT NOT NULL REFERENCES secrets(id) ON DELETE CASCADE,
+        value BLOB NOT NULL,
+        replaced_at INTEGER NOT NULL
+    );
+    CREATE INDEX idx_secret_versions_secret_id ON secret_versions(secret_id);",
+    // 3: soft delete into the trash
+    "ALTER TABLE secrets ADD COLUMN deleted_at INTEGER;",
+    // 4: projects. Names become unique per project, so the secrets table is
+    // rebuilt; existing secrets move to the global project.
+    "CREATE TABLE projects (
+        id TEXT PRIMARY KEY,
+        name TEXT UNIQUE NOT NULL,
+        description TEXT,
+        created_at INTEGER NOT NULL,
+        updated_at INTEGER NOT NULL
+    );
+    INSERT INTO projects (id, name, description, created_at, updated_at)
+    VALUES ('global', 'Global', 'Secrets shared by every project',
+            CAST(strftime('%s', 'now') AS INTEGER), CAST(strftime('%s', 'now') AS INTEGER));
+    CREATE TABLE secrets_new (
+        id TEXT PRIMARY KEY,
+        project_id TEXT NOT NULL DEFAULT 'global' REFERENCES projects(id),
+        name TEXT NOT NULL,
+        description TEXT,
+        value BLOB NOT NULL,
+        created_at INTEGER NOT NULL,
+        updated_at INTEGER NOT NULL,
+        deleted_at INTEGER,
+        UNIQUE (project_id, name)
+    );
+    INSERT INTO secrets_new (id, name, description, value, created_at, updated_at, deleted_at)
+    SELECT id, name, description, value, created_at, updated_at, deleted_at FROM secrets;
+    DROP TABLE secrets;
+    ALTER TABLE secrets_new RENAME TO secrets;",
+    // 5: per-environment values that override a secret's default value
+    "CREATE TABLE environments (
+        name TEXT PRIMARY KEY,
+        created_at INTEGER NOT NULL
+    );
+    INSERT INTO environments (name, created_at)
+    VALUES ('development', CAST(strftime('%s', 'now') AS INTEGER)),
+           ('staging', CAST(strftime('%s', 'now') AS INTEGER)),
+           ('production', CAST(strftime('%s', 'now') AS INTEGER));
+    CREATE TABLE secret_environment_values (
+        secret_id TEXT NOT NULL REFERENCES secrets(id) ON DELETE CASCADE,
+        environment TEXT NOT NULL REFERENCES environments(name) ON UPDATE CASCADE,
+        value BLOB NOT NULL,
+        updated_at INTEGER NOT NULL,
+        PRIMARY KEY (secret_id, environment)
+    );",
+    // 6: tags, many-to-many with secrets
+    "CREATE TABLE tags (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        name TEXT UNIQUE NOT NULL
+    );
+    CREATE TABLE secret_tags (
+        secret_id TEXT NOT NULL REFERENCES secrets(id) ON DELETE CASCADE,
+        tag_id INTEGER NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+        PRIMARY KEY (secret_id, tag_id)
+    );
+    CREATE INDEX idx_secret_tags_tag_id ON secret_tags(tag_id);",
+    // 7: backups of env files replaced by write_env; contents live in
+    // encrypted files under the app data directory
+    "CREATE TABLE env_backups (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        path TEXT NOT NULL,
+        file_name TEXT NOT NULL,
+        size INTEGER NOT NULL,
+        created_at INTEGER NOT NULL
+    );
+    CREATE INDEX idx_env_backups_path ON env_backups(path);",
+    // 8: writes requested by MCP clients, queued until the user decides, and
+    // the rules that approve them without asking
+    "CREATE TABLE write_approvals (
+        id TEXT PRIMARY KEY,
+        client TEXT NOT NULL,
+        tool TEXT NOT NULL,
+        keys TEXT NOT NULL,
+        tag TEXT,
+        path TEXT NOT NULL,
+        project TEXT,
+        status TEXT NOT NULL DEFAULT 'pending',
+        notified INTEGER NOT NULL DEFAULT 0,
+        created_at INTEGER NOT NULL,
+        decided_at INTEGER
+    );
+    CREATE INDEX idx_write_approvals_status ON write_approvals(status);
+    CREATE TABLE approval_rules (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        project_id TEXT REFERENCES projects(id) ON DELETE CASCADE,
+        path TEXT,
+        created_at INTEGER NOT NULL,
+        CHECK ((project_id IS NULL) != (path IS NULL))
+    );",
+    // 9: append-only record of secret access and writes
+    "CREATE TABLE audit_log (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        created_at INTEGER NOT NULL,
+        actor TEXT NOT NULL,
+        operation TEXT NOT NULL,
+        secrets TEXT NOT NULL,
+        path TEXT,
+        success INTEGER NOT NULL,
+        error TEXT
+    );
+    CREATE INDEX idx_audit_log_created_at ON audit_log(created_at);
+    CREATE TRIGGER audit_log_no_update BEFORE UPDATE ON audit_log
+    BEGIN
+        SELECT RAISE(ABORT, 'audit_log is append-only');
+    END;
+    CREATE TRIGGER audit_log_no_delete BEFORE DELETE ON audit_log
+    BEGIN
+        SELECT RAISE(ABORT, 'audit_log is append-only');
+    END;",
+    // 10: chain audit entries by hash; entries from before stay unhashed
+    "ALTER TABLE audit_log ADD COLUMN hash TEXT;",
+    // 11: history of per-environment values; NULL is the default value
+    "ALTER TABLE secret_versions ADD COLUMN environment TEXT
+        REFERENCES environments(name) ON UPDATE CASCADE ON DELETE CASCADE;",
+    // 12: remember where the audit chain starts, so unhashed entries after
+    // it are breaks rather than entries from before hashing
+    "INSERT INTO settings (key, value)
+    SELECT 'audit_chain_start', COALESCE(
+        (SELECT MIN(id) FROM audit_log WHERE hash IS NOT NULL),
+        (SELECT seq + 1 FROM sqlite_sequence WHERE name = 'audit_log'),
+        1
+    );
+    CREATE TRIGGER audit_chain_start_no_update BEFORE UPDATE ON settings
+    WHEN OLD.key = 'audit_chain_start'
+    BEGIN
+        SELECT RAISE(ABORT, 'audit_chain_start cannot be changed');
+    END;
+    CREATE TRIGGER audit_chain_start_no_delete BEFORE DELETE ON settings
+    WHEN OLD.key = 'audit_chain_start'
+    BEGIN
+        SELECT RAISE(ABORT, 'audit_chain_start cannot be changed');
+    END;",
+    // 13: show approvers which values and file format a write would use
+    "ALTER TABLE write_approvals ADD COLUMN environment TEXT;
+    ALTER TABLE write_approvals ADD COLUMN template TEXT;
+    ALTER TABLE write_approvals ADD COLUMN mode TEXT NOT NULL DEFAULT 'overwrite';
+    ALTER TABLE write_approvals ADD COLUMN format TEXT NOT NULL DEFAULT 'dotenv';",
+];
+
+/// Get the schema version stored in the database header
+pub fn schema_version(conn: &Connection) -> Result<i64, String> {
+    conn.pragma_query_value(None, "user_version", |row| row.get(0))
+        .map_err(|e| e.to_string())
+}
+
+/// Bring the database schema up to the latest version.
+/// Foreign keys are off while migrating because steps may rebuild tables that
+/// others reference; dropping the old table would otherwise cascade.
+pub fn migrate(conn: &mut Connection) -> Result<(), String> {
+    let foreign_keys: bool = conn
+        .pragma_query_value(None, "foreign_keys", |row| row.get(0))
+        .map_err(|e| e.to_string())?;
+    conn.pragma_update(None, "foreign_keys", false)
+        .map_err(|e| e.to_string())?;
+
+    let result = apply(conn, MIGRATIONS);
+
+    conn.pragma_update(None, "foreign_keys", foreign_keys)
+        .map_err(|e| e.to_string())?;
+    result
+}
+
+/// Apply each pending step in its own transaction, bumping `user_version`
+/// in the same transaction so a failed step leaves the database untouched
+fn apply(conn: &mut Connection, migrations: &[&str]) -> Result<(), String> {
+    let current = schema_version(conn)?;
+    let latest = migrations.len() as i64;
+
+    if current > latest {
+        return Err(format!(
+            "Database schema version {} is newer than this app supports ({}). Please update Secret MCP.",
+            current, latest
+        ));
+    }
+
+    for (index, sql) in migrations.iter().enumerate().skip(current as usize) {
+        let version = index as i64 + 1;
+        let tx = conn.transaction().map_err(|e| e.to_string())?;
+        tx.execute_batch(sql)
+            .map_err(|e| format!("Migration to version {} failed: {}", version, e))?;
+        tx.pragma_update(None, "user_version", version)
+            .map_err(|e| e.to_string())?;
+        tx.commit().map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LATEST_VERSION: i64 = MIGRATIONS.len() as i64;
+
+    /// Schema and data as written by releases before versioned migrations
+    const V0_FIXTURE: &str = "
+        CREATE TABLE secrets (
+            id TEXT PRIMARY KEY,
+            name TEXT UNIQUE NOT NULL,
+            description TEXT,
+            value TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            updated_at INTEGER NOT NULL
+        );
+        INSERT INTO secrets VALUES ('a', 'OPENAI_API_KEY', 'OpenAI', 'sk-test', 1700000000, 1700000000);
+        INSERT INTO secrets VALUES ('b', 'DATABASE_URL', NULL, 'postgres://localhost', 1700000001, 1700000002);
+    ";
+
+    fn v0_database() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(V0_FIXTURE).unwrap();
+        conn
+    }
+
+    fn table_exists(conn: &Connection, name: &str) -> bool {
+        conn.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = ?",
+            [name],
+            |row| row.get::<_, i64>(0),
+        )
+        .unwrap()
+            == 1
+    }
+
+    #[test]
+    fn upgrades_v0_fixture_to_latest() {
+        let mut conn = v0_database();
+        assert_eq!(schema_version(&conn).unwrap(), 0);
+
+        migrate(&mut conn).unwrap();
+
+        assert_eq!(schema_version(&conn).unwrap(), LATEST_VERSION);
+        assert!(table_exists(&conn, "vault"));
+        assert!(table_exists(&conn, "settings"));
+        assert!(table_exists(&conn, "secret_versions"));
+        assert!(table_exists(&conn, "projects"));
+        assert!(table_exists(&conn, "secret_environment_values"));
+        assert!(table_exists(&conn, "secret_tags"));
+        assert!(table_exists(&conn, "env_backups"));
+        assert!(table_exists(&conn, "write_approvals"));
+        assert!(table_exists(&conn, "approval_rules"));
+        assert!(table_exists(&conn, "audit_log"));
+
+        let trashed: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM secrets WHERE deleted_at IS NOT NULL",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(trashed, 0);
+
+        let names: Vec<String> = conn
+            .prepare("SELECT name FROM secrets ORDER BY name")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(names, vec!["DATABASE_URL", "OPENAI_API_KEY"]);
+
+        let projects: Vec<String> = conn
+            .prepare("SELECT DISTINCT project_id FROM secrets")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(projects, vec!["global"]);
+    }
+
+    #[test]
+    fn project_rebuild_keeps_history() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.pragma_update(None, "foreign_keys", true).unwrap();
+        apply(&mut conn, &MIGRATIONS[..3]).unwrap();
+        conn.execute_batch(
+            "INSERT INTO secrets VALUES ('a', 'API_KEY', NULL, x'00', 1, 1, NULL);
+             INSERT INTO secret_versions (secret_id, value, replaced_at) VALUES ('a', x'01', 2);",
+        )
+        .unwrap();
+
+        migrate(&mut conn).unwrap();
+
+        let versions: i64 = conn
+            .query_row("SELECT COUNT(*) FROM secret_versions", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(versions, 1);
+        conn.execute(
+            "INSERT INTO secrets (id, project_id, name, value, created_at, updated_at)
+             VALUES ('b', 'global', 'API_KEY', x'00', 1, 1)",
+            [],
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn creates_fresh_database() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrate(&mut conn).unwrap();
+
+        assert_eq!(schema_version(&conn).unwrap(), LATEST_VERSION);
+        assert!(table_exists(&conn, "secrets"));
+    }
+
+    #[test]
+    fn migrating_twice_is_a_no_op() {
+        let mut conn = v0_database();
+        migrate(&mut conn).unwrap();
+        migrate(&mut conn).unwrap();
+
+        assert_eq!(schema_version(&conn).unwrap(), LATEST_VERSION);
+    }
+
+    fn audit_chain_start(conn: &Connection) -> String {
+        conn.query_row(
+            "SELECT value FROM settings WHERE key = 'audit_chain_start'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn audit_chain_starts_at_first_hashed_entry() {
+        let insert = "INSERT INTO audit_log (created_at, actor, operation, secrets, success, hash)
+                      VALUES (1, 'desktop', 'get_secret', '[]', 1, ?)";
+
+        // Entries from before hashing, then chained ones
+        let mut conn = Connection::open_in_memory().unwrap();
+        apply(&mut conn, &MIGRATIONS[..11]).unwrap();
+        conn.execute(insert, [None::<&str>]).unwrap();
+        conn.execute(insert, [None::<&str>]).unwrap();
+        conn.execute(insert, [Some("abc")]).unwrap();
+        migrate(&mut conn).unwrap();
+        assert_eq!(audit_chain_start(&conn), "3");
+
+        // Only entries from before hashing: the next one starts the chain
+        let mut conn = Connection::open_in_memory().unwrap();
+        apply(&mut conn, &MIGRATIONS[..11]).unwrap();
+        conn.execute(insert, [None::<&str>]).unwrap();
+        migrate(&mut conn).unwrap();
+        assert_eq!(audit_chain_start(&conn), "2");
+
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrate(&mut conn).unwrap();
+        assert_eq!(audit_chain_start(&conn), "1");
+    }
+
+    #[test]
+    fn refuses_newer_database() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.pragma_update(None, "user_version", LATEST_VERSION + 1)
+            .unwrap();
+
+        let err = migrate(&mut conn).unwrap_err();
+        assert!(err.contains("newer than this app supports"));
+        assert_eq!(schema_version(&conn).unwrap(), LATEST_VERSION + 1);
+    }
+
+    #[test]
+    fn failed_step_rolls_back() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        let steps = [
+            "CREATE TABLE one (id INTEGER);",
+            "CREATE TABLE two (id INTEGER); INSERT INTO missing VALUES (1);",
+        ];
+
+        assert!(apply(&mut conn, &steps).is_err());
+        assert_eq!(schema_version(&conn).unwrap(), 1);
+        assert!(table_exists(&conn, "one"));
+        assert!(!table_exists(&conn, "two"));
+    }
+}
@@ -0,0 +1,463 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path, PathBuf};
+
+/// Where `write_env_file` may write, and `fill_env_from_template` read
+/// templates. Paths are checked after resolving `..` and symlinks, so a link
+/// inside an allowed root cannot point outside it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PathPolicy {
+    /// Directories files may be written under; `~` is the home directory
+    pub allowed_roots: Vec<String>,
+    /// Glob patterns that are refused even inside an allowed root. Patterns
+    /// without `/` match any single path component (`public`, `.bashrc`);
+    /// others match the whole path, with `*` inside a component and `**`
+    /// across components.
+    pub denied_patterns: Vec<String>,
+}
+
+impl Default for PathPolicy {
+    fn default() -> Self {
+        PathPolicy {
+            allowed_roots: vec!["~".to_string()],
+            denied_patterns: [
+                // Shell startup files and credentials
+                ".bashrc",
+                ".bash_profile",
+                ".profile",
+                ".zshrc",
+                ".zprofile",
+                ".ssh",
+                ".gnupg",
+                ".git",
+                // Directories commonly served as-is by web servers
+                "public",
+                "public_html",
+                "htdocs",
+                "www",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        }
+    }
+}
+
+impl PathPolicy {
+    /// Reject roots that cannot be resolved and patterns that are empty
+    pub fn validate(&self) -> Result<(), String> {
+        for root in &self.allowed_roots {
+            if !expand_home(root)?.is_absolute() {
+                return Err(format!("Allowed directory must be absolute: {}", root));
+            }
+        }
+        if self.denied_patterns.iter().any(|p| p.trim().is_empty()) {
+            return Err("Denied patterns cannot be empty".to_string());
+        }
+        Ok(())
+    }
+
+    /// Resolve `path` and check it against the policy, returning the resolved
+    /// path to write to. The file itself need not exist, but its directory must.
+    pub fn check(&self, path: &Path) -> Result<PathBuf, String> {
+        if !path.is_absolute() {
+            return Err("Path must be absolute".to_string());
+        }
+        let file_name = match path.components().next_back() {
+            Some(Component::Normal(name)) => name,
+            _ => return Err(format!("{} is not a file path", path.display())),
+        };
+        let dir = path.parent().unwrap_or(path);
+        let dir = dir
+            .canonicalize()
+            .map_err(|_| format!("Directory does not exist: {}", dir.display()))?;
+        let resolved = dir.join(file_name);
+
+        let allowed = self.allowed_roots.iter().any(|root| {
+            expand_home(root)
+                .and_then(|root| root.canonicalize().map_err(|e| e.to_string()))
+                .is_ok_and(|root| resolved.starts_with(root))
+        });
+        if !allowed {
+            return Err(format!(
+                "{} is outside the allowed directories ({}). Add a directory in Settings to allow it.",
+                resolved.display(),
+                self.allowed_roots.join(", ")
+            ));
+        }
+
+        if let Some(pattern) = self
+            .denied_patterns
+            .iter()
+            .find(|pattern| is_denied(pattern, &resolved))
+        {
+            return Err(format!(
+                "{} matches the denied pattern \"{}\"",
+                resolved.display(),
+                pattern
+            ));
+        }
+
+        Ok(resolved)
+    }
+
+    /// `check`, also refusing anything under `protected` whatever the roots
+    /// allow, such as the app's own data directory
+    pub fn check_outside(&self, path: &Path, protected: &Path) -> Result<PathBuf, String> {
+        let resolved = self.check(path)?;
+        let protected = protected
+            .canonicalize()
+            .unwrap_or_else(|_| protected.to_path_buf());
+        if resolved.starts_with(&protected) {
+            return Err(format!(
+                "{} is inside Secret MCP's data directory, which cannot be used",
+                resolved.display()
+            ));
+        }
+        Ok(resolved)
+    }
+}
+
+/// Replace a leading `~` with the home directory
+fn expand_home(path: &str) -> Result<PathBuf, String> {
+    match path.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with(['/', '\\']) => {
+            let home = dirs::home_dir().ok_or("Home directory not found")?;
+            Ok(home.join(rest.trim_start_matches(['/', '\\'])))
+        }
+        _ => Ok(PathBuf::from(path)),
+    }
+}
+
+fn is_denied(pattern: &str, path: &Path) -> bool {
+    let pattern = pattern.trim();
+    if !pattern.contains('/') {
+        return path.components().any(|component| match component {
+            Component::Normal(name) => glob_match(pattern, &name.to_string_lossy()),
+            _ => false,
+        });
+    }
+    let pattern = match expand_home(pattern) {
+        Ok(pattern) => pattern.to_string_lossy().into_owned(),
+        Err(_) => return false,
+    };
+    glob_match(&pattern, &path.to_string_lossy())
+}
+
+/// Match `text` against a glob: `**` matches anything, `*` anything but `/`,
+/// `?` one character but `/`, `[a-z]` / `[!a-z]` one character in / not in
+/// the class
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    glob_match_from(&pattern, &text)
+}
+
+fn glob_match_from(pattern: &[char], text: &[char]) -> bool {
+    match pattern {
+        [] => text.is_empty(),
+        ['*', '*', rest @ ..] => {
+            // `**/` may also match no directories at all
+            let rest_after_slash = rest.strip_prefix(&['/']).unwrap_or(rest);
+            (0..=text.len()).any(|i| glob_match_from(rest, &text[i..]))
+                || glob_match_from(rest_after_slash, text)
+        }
+        ['*', rest @ ..] => {
+            let segment = text.iter().position(|&c| c == '/').unwrap_or(text.len());
+            (0..=segment).any(|i| glob_match_from(rest, &text[i..]))
+        }
+        ['?', rest @ ..] => {
+            matches!(text, [c, ..] if *c != '/') && glob_match_from(rest, &text[1..])
+        }
+        ['[', rest @ ..] => match class_len(rest) {
+            Some(len) => {
+                matches!(text, [c, ..] if *c != '/' && class_matches(&rest[..len], *c))
+                    && glob_match_from(&rest[len + 1..], &text[1..])
+            }
+            // An unclosed `[` is literal
+            None => matches!(text, ['[', ..]) && glob_match_from(rest, &text[1..]),
+        },
+        [p, rest @ ..] => matches!(text, [c, ..] if c == p) && glob_match_from(rest, &text[1..]),
+    }
+}
+
+/// Length of a `[...]` class body up to its closing `]`, if it is closed.
+/// A `]` right after `[` or `[!` is part of the class.
+fn class_len(class: &[char]) -> Option<usize> {
+    let start = usize::from(matches!(class.first(), Some('!' | '^')));
+    class
+        .iter()
+        .skip(start + 1)
+        .position(|&c| c == ']')
+        .map(|i| i + start + 1)
+}
+
+fn class_matches(class: &[char], c: char) -> bool {
+    let (negated, class) = match class {
+        ['!' | '^', rest @ ..] => (true, rest),
+        _ => (false, class),
+    };
+    let mut found = false;
+    let mut i = 0;
+    while i < class.len() {
+        if i + 2 < class.len() && class[i + 1] == '-' {
+            found |= (class[i]..=class[i + 2]).contains(&c);
+            i += 3;
+        } else {
+            found |= class[i] == c;
+            i += 1;
+        }
+    }
+    found != negated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::{tempdir, TempDir};
+
+    /// A temporary directory with an `allowed` root and an `outside`
+    /// sibling, and a policy allowing only the first
+    fn sandbox(denied_patterns: &[&str]) -> (TempDir, PathBuf, PathBuf, PathPolicy) {
+        let temp = tempdir().unwrap();
+        // The temp directory may itself be behind a symlink
+        let dir = temp.path().canonicalize().unwrap();
+        let allowed = dir.join("allowed");
+        fs::create_dir_all(allowed.join("app")).unwrap();
+        fs::create_dir(dir.join("outside")).unwrap();
+        let policy = PathPolicy {
+            allowed_roots: vec![allowed.to_string_lossy().into_owned()],
+            denied_patterns: denied_patterns.iter().map(|p| p.to_string()).collect(),
+        };
+        (temp, dir, allowed, policy)
+    }
+
+    #[test]
+    fn allows_files_under_a_root() {
+        let (_temp, _, allowed, policy) = sandbox(&[]);
+
+        let resolved = policy.check(&allowed.join("app").join(".env")).unwrap();
+        assert_eq!(resolved, allowed.join("app").join(".env"));
+    }
+
+    #[test]
+    fn rejects_relative_and_non_file_paths() {
+        let (_temp, _, allowed, policy) = sandbox(&[]);
+
+        assert!(policy.check(Path::new("app/.env")).is_err());
+        assert!(policy.check(&allowed.join("app").join("..")).is_err());
+        let err = policy
+            .check(&allowed.join("missing").join(".env"))
+            .unwrap_err();
+        assert!(err.contains("Directory does not exist"), "{}", err);
+    }
+
+    #[test]
+    fn rejects_paths_outside_the_roots() {
+        let (_temp, dir, _, policy) = sandbox(&[]);
+
+        let err = policy.check(&dir.join("outside").join(".env")).unwrap_err();
+        assert!(err.contains("outside the allowed directories"), "{}", err);
+
+        // A sibling sharing the root's name as a prefix is not under it
+        fs::create_dir(dir.join("allowed-not")).unwrap();
+        assert!(policy.check(&dir.join("allowed-not").join(".env")).is_err());
+    }
+
+    #[test]
+    fn resolves_dot_dot_before_checking() {
+        let (_temp, _, allowed, policy) = sandbox(&[]);
+
+        let escape = allowed
+            .join("app")
+            .join("..")
+            .join("..")
+            .join("outside")
+            .join(".env");
+        assert!(policy.check(&escape).is_err());
+
+        let inside = allowed.join("app").join("..").join(".env");
+        assert_eq!(policy.check(&inside).unwrap(), allowed.join(".env"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn follows_symlinked_directories() {
+        let (_temp, dir, allowed, policy) = sandbox(&[]);
+
+        // A link inside the root pointing outside it does not make the
+        // target writable
+        let link = allowed.join("link");
+        std::os::unix::fs::symlink(dir.join("outside"), &link).unwrap();
+        assert!(policy.check(&link.join(".env")).is_err());
+
+        // A link outside the root pointing into it resolves to the root
+        let back = dir.join("outside").join("back");
+        std::os::unix::fs::symlink(allowed.join("app"), &back).unwrap();
+        assert_eq!(
+            policy.check(&back.join(".env")).unwrap(),
+            allowed.join("app").join(".env")
+        );
+    }
+
+    #[test]
+    fn denies_matching_components() {
+        let (_temp, _, allowed, policy) = sandbox(&[".ssh", "public", "*.pem"]);
+        fs::create_dir_all(allowed.join(".ssh")).unwrap();
+        fs::create_dir_all(allowed.join("app").join("public")).unwrap();
+
+        let err = policy
+            .check(&allowed.join(".ssh").join("config"))
+            .unwrap_err();
+        assert!(err.contains("denied pattern \".ssh\""), "{}", err);
+        assert!(policy
+            .check(&allowed.join("app").join("public").join(".env"))
+            .is_err());
+        assert!(policy.check(&allowed.join("app").join("key.pem")).is_err());
+        // Only whole components match
+        assert!(policy
+            .check(&allowed.join("app").join("publication"))
+            .is_ok());
+    }
+
+    #[test]
+    fn denies_matching_paths() {
+        let (_temp, _, allowed, policy) = sandbox(&[]);
+        let policy = PathPolicy {
+            denied_patterns: vec![format!("{}/**/prod.env", allowed.display())],
+            ..policy
+        };
+
+        assert!(policy.check(&allowed.join("prod.env")).is_err());
+        assert!(policy.check(&allowed.join("app").join("prod.env")).is_err());
+        assert!(policy.check(&allowed.join("app").join("dev.env")).is_ok());
+    }
+
+    #[test]
+    fn protected_directory_is_always_denied() {
+        let (_temp, _, allowed, policy) = sandbox(&[]);
+        let data = allowed.join("app").join("secret-mcp");
+        fs::create_dir_all(data.join("env-backups")).unwrap();
+
+        let err = policy
+            .check_outside(&data.join("mcp-http.json"), &data)
+            .unwrap_err();
+        assert!(err.contains("data directory"), "{}", err);
+        assert!(policy
+            .check_outside(&data.join("env-backups").join("x.enc"), &data)
+            .is_err());
+        assert!(policy
+            .check_outside(&allowed.join("app").join(".env"), &data)
+            .is_ok());
+
+        // Even when it is itself an allowed root
+        let inside = PathPolicy {
+            allowed_roots: vec![data.to_string_lossy().into_owned()],
+            denied_patterns: Vec::new(),
+        };
+        assert!(inside.check(&data.join("secrets.db")).is_ok());
+        assert!(inside
+            .check_outside(&data.join("secrets.db"), &data)
+            .is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn protected_directory_is_denied_through_symlinks() {
+        let (_temp, dir, allowed, policy) = sandbox(&[]);
+        let data = dir.join("outside").join("secret-mcp");
+        fs::create_dir(&data).unwrap();
+        let link = allowed.join("data");
+        std::os::unix::fs::symlink(&data, &link).unwrap();
+
+        let policy = PathPolicy {
+            allowed_roots: vec![dir.to_string_lossy().into_owned()],
+            ..policy
+        };
+        assert!(policy.check(&link.join("secrets.db")).is_ok());
+        assert!(policy
+            .check_outside(&link.join("secrets.db"), &data)
+            .is_err());
+    }
+
+    #[test]
+    fn default_policy_denies_shell_startup_files() {
+        let policy = PathPolicy::default();
+
+        assert!(is_denied_by_any(&policy, "/home/me/.bashrc"));
+        assert!(is_denied_by_any(&policy, "/home/me/.ssh/authorized_keys"));
+        assert!(is_denied_by_any(&policy, "/srv/site/public/.env"));
+        assert!(!is_denied_by_any(&policy, "/home/me/app/.env"));
+    }
+
+    fn is_denied_by_any(policy: &PathPolicy, path: &str) -> bool {
+        policy
+            .denied_patterns
+            .iter()
+            .any(|pattern| is_denied(pattern, Path::new(path)))
+    }
+
+    #[test]
+    fn validates_roots_and_patterns() {
+        assert!(PathPolicy::default().validate().is_ok());
+        let relative = PathPolicy {
+            allowed_roots: vec!["projects".to_string()],
+            ..PathPolicy::default()
+        };
+        assert!(relative.validate().is_err());
+        let empty = PathPolicy {
+            denied_patterns: vec![" ".to_string()],
+            ..PathPolicy::default()
+        };
+        assert!(empty.validate().is_err());
+    }
+
+    #[test]
+    fn star_stays_within_a_component() {
+        assert!(glob_match("*.env", ".env"));
+        assert!(glob_match("*.env", "prod.env"));
+        assert!(!glob_match("*.env", "app/prod.env"));
+        assert!(glob_match("app/*/.env", "app/web/.env"));
+        assert!(!glob_match("app/*/.env", "app/web/api/.env"));
+        assert!(glob_match("?.env", "a.env"));
+        assert!(!glob_match("?.env", "/.env"));
+        assert!(!glob_match("?.env", "ab.env"));
+    }
+
+    #[test]
+    fn double_star_crosses_components() {
+        assert!(glob_match("/srv/**/.env", "/srv/a/b/c/.env"));
+        assert!(glob_match("/srv/**/.env", "/srv/.env"));
+        assert!(glob_match("/srv/**", "/srv/a/b"));
+        assert!(glob_match("**/secrets", "/home/me/secrets"));
+        assert!(!glob_match("/srv/**/.env", "/var/srv/.env"));
+        assert!(!glob_match("/srv/**/.env", "/srv/a/.envrc"));
+    }
+
+    #[test]
+    fn character_classes() {
+        assert!(glob_match("[abc].env", "b.env"));
+        assert!(!glob_match("[abc].env", "d.env"));
+        assert!(glob_match("[a-c][0-9]", "b7"));
+        assert!(!glob_match("[a-c][0-9]", "b_"));
+        assert!(glob_match("[!a-c]", "d"));
+        assert!(!glob_match("[!a-c]", "a"));
+        assert!(glob_match("[^a-c]", "z"));
+        // A leading `]` is a member; a class never matches `/`
+        assert!(glob_match("[]x]", "]"));
+        assert!(!glob_match("a[!x]b", "a/b"));
+        // An unclosed `[` is literal
+        assert!(glob_match("[abc", "[abc"));
+        assert!(!glob_match("[abc", "a"));
+    }
+
+    #[test]
+    fn class_ranges_and_negation() {
+        assert!(class_matches(&['a', '-', 'f'], 'c'));
+        assert!(!class_matches(&['a', '-', 'f'], 'g'));
+        assert!(class_matches(&['!', 'a', '-', 'f'], 'g'));
+        assert!(class_matches(&['x', 'a', '-', 'c'], 'x'));
+        // A trailing `-` is literal
+        assert!(class_matches(&['a', '-'], '-'));
+    }
+}
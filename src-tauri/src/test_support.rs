@@ -0,0 +1,71 @@
+//! Setup shared by tests that use the app's database and vault
+
+use crate::db;
+use crate::path_policy::PathPolicy;
+use crate::vault;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+use tempfile::TempDir;
+
+/// The database and data key are global, so tests using them take turns
+static LOCK: Mutex<()> = Mutex::new(());
+
+/// A fresh database in a temporary data directory, with the vault unlocked
+/// and writes allowed under `files`, for as long as it is alive
+pub struct TestApp {
+    files: PathBuf,
+    _temp: TempDir,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl TestApp {
+    pub fn new() -> Self {
+        let lock = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let temp = tempfile::tempdir().unwrap();
+        // The temp directory may itself be behind a symlink
+        let root = temp.path().canonicalize().unwrap();
+        let data = root.join("data");
+        let files = root.join("files");
+        std::fs::create_dir(&data).unwrap();
+        std::fs::create_dir(&files).unwrap();
+
+        *db::TEST_APP_DIR.lock().unwrap() = Some(data);
+        db::init_db().unwrap();
+        vault::unlock_with_new_key().unwrap();
+        db::set_path_policy(&PathPolicy {
+            allowed_roots: vec![files.to_string_lossy().into_owned()],
+            ..PathPolicy::default()
+        })
+        .unwrap();
+
+        TestApp {
+            files,
+            _temp: temp,
+            _lock: lock,
+        }
+    }
+
+    /// Path of `name` in the directory writes are allowed in
+    pub fn file(&self, name: &str) -> PathBuf {
+        self.files.join(name)
+    }
+
+    /// Write `content` to `name` in the allowed directory, returning its path
+    pub fn write(&self, name: &str, content: &str) -> String {
+        let path = self.file(name);
+        std::fs::write(&path, content).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    /// Content of `name` in the allowed directory
+    pub fn read(&self, name: &str) -> String {
+        std::fs::read_to_string(self.file(name)).unwrap()
+    }
+}
+
+impl Drop for TestApp {
+    fn drop(&mut self) {
+        let _ = vault::lock();
+        *db::TEST_APP_DIR.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+}
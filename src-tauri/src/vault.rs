@@ -0,0 +1,227 @@
+use crate::crypto::{self, KdfParams};
+use chacha20poly1305::Key;
+use once_cell::sync::Lazy;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Mutex;
+use zeroize::Zeroize;
+
+/// Error returned by every operation that needs the vault to be unlocked
+pub const VAULT_LOCKED: &str = "Vault is locked";
+
+/// Minimum length accepted when setting the master password
+const MIN_PASSWORD_LEN: usize = 8;
+
+/// Vault data key, present only while the vault is unlocked
+static DATA_KEY: Lazy<Mutex<Option<Key>>> = Lazy::new(|| Mutex::new(None));
+
+/// Lock state reported to the frontend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VaultStatus {
+    /// Whether a master password has been set
+    pub initialized: bool,
+    pub unlocked: bool,
+}
+
+/// Master password parameters and the wrapped data key
+struct VaultRecord {
+    salt: Vec<u8>,
+    params: KdfParams,
+    wrapped_key: Vec<u8>,
+}
+
+fn load_record(conn: &Connection) -> Result<Option<VaultRecord>, String> {
+    conn.query_row(
+        "SELECT kdf_salt, kdf_m_cost, kdf_t_cost, kdf_p_cost, wrapped_key FROM vault WHERE id = 1",
+        [],
+        |row| {
+            Ok(VaultRecord {
+                salt: row.get(0)?,
+                params: KdfParams {
+                    m_cost: row.get(1)?,
+                    t_cost: row.get(2)?,
+                    p_cost: row.get(3)?,
+                },
+                wrapped_key: row.get(4)?,
+            })
+        },
+    )
+    .optional()
+    .map_err(|e| e.to_string())
+}
+
+/// Get the current lock state
+pub fn status(conn: &Connection) -> Result<VaultStatus, String> {
+    Ok(VaultStatus {
+        initialized: load_record(conn)?.is_some(),
+        unlocked: is_unlocked()?,
+    })
+}
+
+/// Unlock the vault with the master password.
+/// If no master password is set yet, this sets it, adopting the data key
+/// from `legacy_key_path` when a previous version left one on disk.
+pub fn unlock(conn: &Connection, password: &str, legacy_key_path: &Path) -> Result<(), String> {
+    let key = match load_record(conn)? {
+        Some(record) => {
+            let mut kek = crypto::derive_key(password, &record.salt, &record.params)?;
+            let key = crypto::unwrap_key(&kek, &record.wrapped_key);
+            kek.as_mut_slice().zeroize();
+            key.map_err(|_| "Incorrect master password".to_string())?
+        }
+        None => setup(conn, password, legacy_key_path)?,
+    };
+    set_key(key)
+}
+
+/// Hold `key` as the data key, wiping the previous one
+fn set_key(key: Key) -> Result<(), String> {
+    let mut data_key = DATA_KEY.lock().map_err(|e| e.to_string())?;
+    if let Some(mut old) = data_key.replace(key) {
+        old.as_mut_slice().zeroize();
+    }
+    Ok(())
+}
+
+/// Set the master password for a vault that does not have one
+fn setup(conn: &Connection, password: &str, legacy_key_path: &Path) -> Result<Key, String> {
+    if password.chars().count() < MIN_PASSWORD_LEN {
+        return Err(format!(
+            "Master password must be at least {} characters",
+            MIN_PASSWORD_LEN
+        ));
+    }
+
+    let key = match crypto::read_key_file(legacy_key_path)? {
+        Some(key) => key,
+        None => crypto::generate_key(),
+    };
+
+    let salt = crypto::generate_salt();
+    let params = KdfParams::default();
+    let mut kek = crypto::derive_key(password, &salt, &params)?;
+    let wrapped_key = crypto::wrap_key(&kek, &key);
+    kek.as_mut_slice().zeroize();
+    let wrapped_key = wrapped_key?;
+
+    conn.execute(
+        "INSERT INTO vault (id, kdf_salt, kdf_m_cost, kdf_t_cost, kdf_p_cost, wrapped_key) VALUES (1, ?, ?, ?, ?, ?)",
+        params![salt, params.m_cost, params.t_cost, params.p_cost, wrapped_key],
+    )
+    .map_err(|e| e.to_string())?;
+
+    // The data key is now protected by the master password only
+    if legacy_key_path.exists() {
+        std::fs::remove_file(legacy_key_path).map_err(|e| e.to_string())?;
+    }
+
+    Ok(key)
+}
+
+/// Unlock with a fresh data key, skipping the slow key derivation
+#[cfg(test)]
+pub fn unlock_with_new_key() -> Result<(), String> {
+    set_key(crypto::generate_key())
+}
+
+/// Zeroize the data key and drop it from memory
+pub fn lock() -> Result<(), String> {
+    let mut data_key = DATA_KEY.lock().map_err(|e| e.to_string())?;
+    if let Some(mut key) = data_key.take() {
+        key.as_mut_slice().zeroize();
+    }
+    Ok(())
+}
+
+/// Whether the data key is currently loaded
+pub fn is_unlocked() -> Result<bool, String> {
+    let data_key = DATA_KEY.lock().map_err(|e| e.to_string())?;
+    Ok(data_key.is_some())
+}
+
+/// Fail with `VAULT_LOCKED` unless the vault is unlocked
+pub fn ensure_unlocked() -> Result<(), String> {
+    if is_unlocked()? {
+        Ok(())
+    } else {
+        Err(VAULT_LOCKED.to_string())
+    }
+}
+
+/// Run `f` with the data key, failing with `VAULT_LOCKED` if the vault is locked
+pub fn with_key<T, F: FnOnce(&Key) -> Result<T, String>>(f: F) -> Result<T, String> {
+    let data_key = DATA_KEY.lock().map_err(|e| e.to_string())?;
+    let key = data_key.as_ref().ok_or(VAULT_LOCKED)?;
+    f(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::migrations;
+    use crate::test_support::TestApp;
+
+    /// A database without a master password, and the global key locked
+    fn new_vault() -> (TestApp, Connection, tempfile::TempDir) {
+        let app = TestApp::new();
+        lock().unwrap();
+        let mut conn = Connection::open_in_memory().unwrap();
+        migrations::migrate(&mut conn).unwrap();
+        (app, conn, tempfile::tempdir().unwrap())
+    }
+
+    #[test]
+    fn first_unlock_sets_the_password() {
+        let (_app, conn, dir) = new_vault();
+        let no_key_file = dir.path().join("key");
+        assert!(!status(&conn).unwrap().initialized);
+
+        unlock(&conn, "correct horse", &no_key_file).unwrap();
+        let key = with_key(|key| Ok(*key)).unwrap();
+        lock().unwrap();
+        assert!(status(&conn).unwrap().initialized);
+        assert!(!status(&conn).unwrap().unlocked);
+
+        assert_eq!(
+            unlock(&conn, "wrong horse", &no_key_file).unwrap_err(),
+            "Incorrect master password"
+        );
+        assert!(!is_unlocked().unwrap());
+
+        unlock(&conn, "correct horse", &no_key_file).unwrap();
+        assert_eq!(with_key(|key| Ok(*key)).unwrap(), key);
+    }
+
+    #[test]
+    fn password_must_be_long_enough() {
+        let (_app, conn, dir) = new_vault();
+
+        let error = unlock(&conn, "short", &dir.path().join("key")).unwrap_err();
+
+        assert_eq!(error, "Master password must be at least 8 characters");
+        assert!(!status(&conn).unwrap().initialized);
+        assert!(!is_unlocked().unwrap());
+    }
+
+    #[test]
+    fn legacy_key_file_is_adopted_then_removed() {
+        let (_app, conn, dir) = new_vault();
+        let key_file = dir.path().join("key");
+        let legacy = crypto::generate_key();
+        std::fs::write(&key_file, legacy.as_slice()).unwrap();
+
+        unlock(&conn, "correct horse", &key_file).unwrap();
+
+        assert_eq!(with_key(|key| Ok(*key)).unwrap(), legacy);
+        assert!(!key_file.exists());
+    }
+
+    #[test]
+    fn locked_vault_refuses_with_its_own_error() {
+        let (_app, _conn, _dir) = new_vault();
+
+        assert_eq!(ensure_unlocked().unwrap_err(), VAULT_LOCKED);
+        assert_eq!(with_key(|_| Ok(())).unwrap_err(), VAULT_LOCKED);
+    }
+}
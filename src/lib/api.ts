@@ -1,9 +1,12 @@
 import { invoke } from "@tauri-apps/api/core";
+import { listen, type UnlistenFn } from "@tauri-apps/api/event";
 
 export interface SecretInfo {
   id: string;
+  project_id: string;
   name: string;
   description: string | null;
+  tags: string[];
   created_at: number;
   updated_at: number;
 }
@@ -12,14 +15,178 @@ export interface Secret extends SecretInfo {
   value: string;
 }
 
+export interface SecretVersionInfo {
+  id: number;
+  secret_id: string;
+  environment: string | null;
+  replaced_at: number;
+}
+
+export interface TrashedSecretInfo {
+  id: string;
+  project_id: string;
+  name: string;
+  description: string | null;
+  deleted_at: number;
+}
+
+export interface Project {
+  id: string;
+  name: string;
+  description: string | null;
+  created_at: number;
+  updated_at: number;
+}
+
+export const GLOBAL_PROJECT_ID = "global";
+
+export interface Environment {
+  name: string;
+  created_at: number;
+}
+
+export interface SecretEnvironmentValue {
+  environment: string;
+  value: string;
+  updated_at: number;
+}
+
+export interface EnvDiff {
+  added: string[];
+  changed: string[];
+  unchanged: string[];
+  removed: string[];
+  not_comparable: string[];
+  missing: string[];
+}
+
+export interface KeyMapping {
+  variable: string;
+  secret: string;
+  found: boolean;
+}
+
+export type GitPolicy = "refuse" | "warn" | "add_to_gitignore";
+
+export type OutputFormat =
+  | "dotenv"
+  | "shell"
+  | "json"
+  | "yaml"
+  | "toml"
+  | "docker"
+  | "systemd"
+  | "direnv"
+  | "kubernetes"
+  | "sealedsecret";
+
+export interface KubernetesOptions {
+  name: string;
+  namespace?: string | null;
+  labels?: Record<string, string>;
+}
+
+export interface WriteEnvOptions {
+  project?: string | null;
+  environment?: string | null;
+  tag?: string | null;
+  mode?: "overwrite" | "merge";
+  format?: OutputFormat | null;
+  kubernetes?: KubernetesOptions | null;
+  git?: GitPolicy;
+  aliases?: Record<string, string>;
+  prefix?: string | null;
+}
+
 export interface WriteEnvResult {
   success: boolean;
   written: number;
   missing: string[];
+  diff: EnvDiff | null;
+  git: "not_in_repository" | "ignored" | "not_ignored" | "tracked" | "added_to_gitignore";
+  mappings: KeyMapping[];
+}
+
+export interface EnvBackup {
+  id: number;
+  path: string;
+  size: number;
+  created_at: number;
+}
+
+export interface PathPolicy {
+  allowed_roots: string[];
+  denied_patterns: string[];
 }
 
-export async function listSecrets(): Promise<SecretInfo[]> {
-  return await invoke("list_secrets");
+export interface McpHttpStatus {
+  running: boolean;
+  url: string | null;
+  token: string | null;
+}
+
+export interface WriteApproval {
+  id: string;
+  client: string;
+  tool: string;
+  keys: string[];
+  tag: string | null;
+  path: string;
+  project: string | null;
+  environment: string | null;
+  template: string | null;
+  mode: "overwrite" | "merge";
+  format: OutputFormat;
+  created_at: number;
+}
+
+export type ApprovalScope = "project" | "path";
+
+export interface ApprovalRule {
+  id: number;
+  project_id: string | null;
+  project: string | null;
+  path: string | null;
+  created_at: number;
+}
+
+export interface AuditEntry {
+  id: number;
+  created_at: number;
+  actor: string;
+  operation: string;
+  secrets: string[];
+  path: string | null;
+  success: boolean;
+  error: string | null;
+}
+
+export interface AuditFilter {
+  actor?: string | null;
+  operation?: string | null;
+  secret?: string | null;
+  path?: string | null;
+  since?: number | null;
+  until?: number | null;
+  success?: boolean | null;
+  limit?: number | null;
+}
+
+export interface AuditVerification {
+  valid: boolean;
+  entries: number;
+  unchained: number;
+  first_broken: number | null;
+  last_hash: string | null;
+}
+
+export interface VaultStatus {
+  initialized: boolean;
+  unlocked: boolean;
+}
+
+export async function listSecrets(projectId: string | null = null): Promise<SecretInfo[]> {
+  return await invoke("list_secrets", { projectId });
 }
 
 export async function getSecret(id: string): Promise<Secret | null> {
@@ -29,10 +196,12 @@ export async function getSecret(id: string): Promise<Secret | null> {
 export async function createSecret(
   name: string,
   description: string | null,
-  value: string
+  value: string,
+  projectId: string | null = null,
+  tags: string[] = []
 ): Promise<Secret> {
   return await invoke("create_secret", {
-    input: { name, description, value },
+    input: { name, description, value, project_id: projectId, tags },
   });
 }
 
@@ -40,10 +209,12 @@ export async function updateSecret(
   id: string,
   name: string,
   description: string | null,
-  value: string
+  value: string,
+  projectId: string | null = null,
+  tags: string[] | null = null
 ): Promise<Secret> {
   return await invoke("update_secret", {
-    input: { id, name, description, value },
+    input: { id, name, description, value, project_id: projectId, tags },
   });
 }
 
@@ -51,6 +222,233 @@ export async function deleteSecret(id: string): Promise<boolean> {
   return await invoke("delete_secret", { id });
 }
 
+export async function listProjects(): Promise<Project[]> {
+  return await invoke("list_projects");
+}
+
+export async function createProject(name: string, description: string | null): Promise<Project> {
+  return await invoke("create_project", { input: { name, description } });
+}
+
+export async function updateProject(
+  id: string,
+  name: string,
+  description: string | null
+): Promise<Project> {
+  return await invoke("update_project", { id, input: { name, description } });
+}
+
+export async function deleteProject(id: string): Promise<boolean> {
+  return await invoke("delete_project", { id });
+}
+
+export async function listEnvironments(): Promise<Environment[]> {
+  return await invoke("list_environments");
+}
+
+export async function createEnvironment(name: string): Promise<Environment> {
+  return await invoke("create_environment", { name });
+}
+
+export async function renameEnvironment(name: string, newName: string): Promise<boolean> {
+  return await invoke("rename_environment", { name, newName });
+}
+
+export async function deleteEnvironment(name: string): Promise<boolean> {
+  return await invoke("delete_environment", { name });
+}
+
+export async function listSecretEnvironmentValues(
+  secretId: string
+): Promise<SecretEnvironmentValue[]> {
+  return await invoke("list_secret_environment_values", { secretId });
+}
+
+export async function setSecretEnvironmentValue(
+  secretId: string,
+  environment: string,
+  value: string
+): Promise<void> {
+  return await invoke("set_secret_environment_value", { secretId, environment, value });
+}
+
+export async function clearSecretEnvironmentValue(
+  secretId: string,
+  environment: string
+): Promise<boolean> {
+  return await invoke("clear_secret_environment_value", { secretId, environment });
+}
+
+export async function listTags(): Promise<string[]> {
+  return await invoke("list_tags");
+}
+
+export async function listTrash(): Promise<TrashedSecretInfo[]> {
+  return await invoke("list_trash");
+}
+
+export async function restoreSecret(id: string): Promise<boolean> {
+  return await invoke("restore_secret", { id });
+}
+
+export async function purgeTrash(id: string | null): Promise<number> {
+  return await invoke("purge_trash", { id });
+}
+
+export async function listSecretVersions(secretId: string): Promise<SecretVersionInfo[]> {
+  return await invoke("list_secret_versions", { secretId });
+}
+
+export async function restoreSecretVersion(versionId: number): Promise<Secret> {
+  return await invoke("restore_secret_version", { versionId });
+}
+
+export async function listEnvBackups(path: string | null = null): Promise<EnvBackup[]> {
+  return await invoke("list_env_backups", { path });
+}
+
+export async function restoreEnvBackup(id: number, git: GitPolicy | null = null): Promise<EnvBackup> {
+  return await invoke("restore_env_backup", { id, git });
+}
+
+export async function writeEnv(
+  keys: string[],
+  path: string,
+  options: WriteEnvOptions | null = null
+): Promise<WriteEnvResult> {
+  return await invoke("write_env", { keys, path, options });
+}
+
+export async function fillEnvFromTemplate(
+  template: string,
+  path: string,
+  options: WriteEnvOptions | null = null
+): Promise<WriteEnvResult> {
+  return await invoke("fill_env_from_template", { template, path, options });
+}
+
+export async function diffEnv(
+  keys: string[],
+  path: string,
+  options: WriteEnvOptions | null = null
+): Promise<EnvDiff> {
+  return await invoke("diff_env", { keys, path, options });
+}
+
 export async function getDbPath(): Promise<string> {
   return await invoke("get_db_path");
 }
+
+export async function vaultStatus(): Promise<VaultStatus> {
+  return await invoke("vault_status");
+}
+
+export async function unlockVault(password: string): Promise<VaultStatus> {
+  return await invoke("unlock_vault", { password });
+}
+
+export async function lockVault(): Promise<VaultStatus> {
+  return await invoke("lock_vault");
+}
+
+export async function getAutoLockMinutes(): Promise<number> {
+  return await invoke("get_auto_lock_minutes");
+}
+
+export async function setAutoLockMinutes(minutes: number): Promise<void> {
+  return await invoke("set_auto_lock_minutes", { minutes });
+}
+
+export async function onVaultLocked(callback: () => void): Promise<UnlistenFn> {
+  return await listen("vault-locked", callback);
+}
+
+export async function getVersionRetention(): Promise<number> {
+  return await invoke("get_version_retention");
+}
+
+export async function setVersionRetention(versions: number): Promise<void> {
+  return await invoke("set_version_retention", { versions });
+}
+
+export async function getTrashRetentionDays(): Promise<number> {
+  return await invoke("get_trash_retention_days");
+}
+
+export async function setTrashRetentionDays(days: number): Promise<void> {
+  return await invoke("set_trash_retention_days", { days });
+}
+
+export async function getEnvBackupRetention(): Promise<number> {
+  return await invoke("get_env_backup_retention");
+}
+
+export async function setEnvBackupRetention(backups: number): Promise<void> {
+  return await invoke("set_env_backup_retention", { backups });
+}
+
+export async function getPathPolicy(): Promise<PathPolicy> {
+  return await invoke("get_path_policy");
+}
+
+export async function setPathPolicy(policy: PathPolicy): Promise<void> {
+  return await invoke("set_path_policy", { policy });
+}
+
+export async function startMcpHttp(port: number | null = null): Promise<McpHttpStatus> {
+  return await invoke("start_mcp_http", { port });
+}
+
+export async function stopMcpHttp(): Promise<McpHttpStatus> {
+  return await invoke("stop_mcp_http");
+}
+
+export async function getMcpHttpStatus(): Promise<McpHttpStatus> {
+  return await invoke("get_mcp_http_status");
+}
+
+export async function getMcpHttpEnabled(): Promise<boolean> {
+  return await invoke("get_mcp_http_enabled");
+}
+
+export async function setMcpHttpEnabled(enabled: boolean): Promise<void> {
+  return await invoke("set_mcp_http_enabled", { enabled });
+}
+
+export async function listWriteApprovals(): Promise<WriteApproval[]> {
+  return await invoke("list_write_approvals");
+}
+
+export async function onWriteApprovalRequested(
+  callback: (approval: WriteApproval) => void
+): Promise<UnlistenFn> {
+  return await listen<WriteApproval>("write-approval-requested", (event) => callback(event.payload));
+}
+
+export async function approveWrite(id: string, remember: ApprovalScope | null = null): Promise<void> {
+  return await invoke("approve_write", { id, remember });
+}
+
+export async function denyWrite(id: string): Promise<void> {
+  return await invoke("deny_write", { id });
+}
+
+export async function listApprovalRules(): Promise<ApprovalRule[]> {
+  return await invoke("list_approval_rules");
+}
+
+export async function deleteApprovalRule(id: number): Promise<boolean> {
+  return await invoke("delete_approval_rule", { id });
+}
+
+export async function queryAuditLog(filter: AuditFilter | null = null): Promise<AuditEntry[]> {
+  return await invoke("query_audit_log", { filter });
+}
+
+export async function exportAuditLog(filter: AuditFilter | null, path: string): Promise<number> {
+  return await invoke("export_audit_log", { filter, path });
+}
+
+export async function verifyAuditLog(): Promise<AuditVerification> {
+  return await invoke("verify_audit_log");
+}